@@ -1,6 +1,10 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
-use arirs::{client::Client, Event};
+use arirs::{
+    client::{Client, ReconnectPolicy},
+    Event,
+};
+use futures_util::StreamExt;
 use tracing::{debug, error};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
@@ -11,35 +15,40 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with(EnvFilter::from_default_env())
         .init();
 
-    let (tx, mut rx) = tokio::sync::mpsc::channel(1024);
-    let dtmf_buffer = Arc::new(Mutex::new(String::new()));
+    let client = Arc::new(
+        Client::new()
+            .url(url::Url::parse("http://localhost:8088/ari/")?)
+            .username("asterisk")
+            .password("asterisk")
+            .app_name("ari")
+            .reconnect(ReconnectPolicy::unlimited())
+            .build()?,
+    );
 
-    let client = Client::new()
-        .url(url::Url::parse("http://localhost:8088/ari/")?)
-        .username("asterisk")
-        .password("asterisk")
-        .app_name("ari")
-        .handler(tx)
-        .build()?;
+    let mut stasis_starts = client.stasis_starts();
 
+    let client_clone = client.clone();
     tokio::spawn(async move {
-        if let Err(e) = client.run().await {
+        if let Err(e) = client_clone.run().await {
             error!("Error: {}", e);
         }
     });
 
-    while let Some(event) = rx.recv().await {
-        match event {
-            Event::ChannelDtmfReceived(event) => {
-                debug!("Received DTMF: {}", event.digit);
-                dtmf_buffer.lock().unwrap().push_str(&event.digit);
-            }
-            Event::StasisEnd(_) => {
-                debug!("Stasis ended, DTMF buffer: {}", dtmf_buffer.lock().unwrap());
-                dtmf_buffer.lock().unwrap().clear();
+    while let Some((stasis_start, mut events)) = stasis_starts.next().await {
+        debug!("Channel {} entered the application", stasis_start.channel.id);
+
+        tokio::spawn(async move {
+            let mut dtmf_buffer = String::new();
+
+            while let Some(event) = events.next().await {
+                if let Event::ChannelDtmfReceived(event) = event {
+                    debug!("Received DTMF: {}", event.digit);
+                    dtmf_buffer.push_str(&event.digit);
+                }
             }
-            _ => {}
-        }
+
+            debug!("Stasis ended, DTMF buffer: {}", dtmf_buffer);
+        });
     }
 
     Ok(())
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use arirs::{
+    bridge::{Bridge, BridgeType},
+    channel::{Channel, ExternalMediaRequest},
+    client::Client,
+    external_media::RtpEndpoint,
+    Event,
+};
+use futures_util::StreamExt;
+use tracing::{error, level_filters::LevelFilter};
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+const APP_NAME: &str = "ari";
+
+// 20ms of signed linear audio at 16kHz
+const FRAME_SAMPLES: u32 = 320;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::registry().with(fmt::layer()).with(LevelFilter::TRACE).init();
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel(1024);
+
+    let client = Arc::new(
+        Client::new()
+            .url(url::Url::parse("http://localhost:8088/ari")?)
+            .username("asterisk")
+            .password("asterisk")
+            .app_name(APP_NAME)
+            .handler(tx)
+            .build()?,
+    );
+
+    let client_clone = client.clone();
+    tokio::spawn(async move {
+        if let Err(e) = client_clone.run().await {
+            error!("Error: {}", e);
+        }
+    });
+
+    while let Some(event) = rx.recv().await {
+        let Event::StasisStart(e) = event else {
+            continue;
+        };
+
+        // the external media channel enters the application as well
+        if e.channel.name.starts_with("UnicastRTP/") {
+            continue;
+        }
+
+        let client = client.clone();
+        tokio::spawn(async move {
+            let rtp = RtpEndpoint::bind("127.0.0.1:0").await?;
+
+            let bridge = Bridge::create_bridge(&client, vec![BridgeType::Mixing], None).await?;
+            let external_host = rtp.external_host()?;
+            let external = Channel::start_external_media(&client, ExternalMediaRequest::new(APP_NAME, &external_host, "slin16")).await?;
+
+            bridge.add_channel(&client, &e.channel.id, None, false, false).await?;
+            bridge.add_channel(&client, &external.id, None, false, false).await?;
+
+            // echo the caller's audio back to them
+            let mut frames = Box::pin(rtp.frames());
+            while let Some(frame) = frames.next().await {
+                rtp.send(&frame?.payload, FRAME_SAMPLES).await?;
+            }
+
+            Ok::<_, arirs::AriError>(())
+        });
+    }
+
+    Ok(())
+}
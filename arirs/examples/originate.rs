@@ -1,7 +1,5 @@
-use std::collections::HashMap;
-
 use arirs::{
-    channel::{Channel, OriginateParams},
+    channel::{Channel, OriginateRequest},
     client::Client,
     Result,
 };
@@ -25,18 +23,7 @@ async fn main() -> Result<()> {
 
     Channel::originate(
         &client,
-        "PJSIP/1000",
-        OriginateParams::Application {
-            app: APP_NAME,
-            app_args: vec![],
-        },
-        None,
-        None,
-        None,
-        None,
-        None,
-        vec!["alaw,ulaw"],
-        HashMap::new(),
+        OriginateRequest::application("PJSIP/1000", APP_NAME).formats(vec!["alaw", "ulaw"]),
     )
     .await?;
 
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arirs::{client::Client, Event};
+use arirs::{channel::Reason, client::Client, Event};
 use tracing::{error, level_filters::LevelFilter};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -30,12 +30,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     while let Some(event) = rx.recv().await {
-        match event {
-            Event::StasisStart(e) => {
-                let channel = e.channel;
-                channel.play_media(&client, "sound:hello", Some("en"), None, None, None).await?;
-            }
-            _ => {}
+        if let Event::StasisStart(e) = event {
+            let channel = e.channel;
+            let client = client.clone();
+
+            tokio::spawn(async move {
+                for media in ["sound:hello", "sound:goodbye"] {
+                    let playback = channel.play_media(&client, media, Some("en"), None, None, None).await?;
+                    playback.finished(&client).await?;
+                }
+
+                channel.hangup(&client, Reason::Normal).await
+            });
         }
     }
 
@@ -0,0 +1,195 @@
+use chrono::DateTime;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::{event, instrument, Level};
+
+use crate::{client::Client, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Application {
+    pub name: String,
+    pub channel_ids: Vec<String>,
+    pub bridge_ids: Vec<String>,
+    pub endpoint_ids: Vec<String>,
+    pub device_names: Vec<String>,
+    #[serde(default)]
+    pub events_allowed: Vec<EventFilter>,
+    #[serde(default)]
+    pub events_disallowed: Vec<EventFilter>,
+}
+
+/// An event type allowed or disallowed for an application, e.g. `ChannelDtmfReceived`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EventFilter {
+    #[serde(rename = "type")]
+    pub event_type: String,
+}
+
+/// A resource an application can subscribe to.
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum EventSource {
+    #[display("channel:{_0}")]
+    Channel(String),
+    #[display("bridge:{_0}")]
+    Bridge(String),
+    /// Technology and resource of the endpoint.
+    #[display("endpoint:{_0}/{_1}")]
+    Endpoint(String, String),
+    /// Every endpoint of a technology.
+    #[display("endpoint:{_0}")]
+    Technology(String),
+    #[display("deviceState:{_0}")]
+    DeviceState(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ApplicationReplaced {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+impl Application {
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client) -> Result<Vec<Application>> {
+        let url = client.url.join("applications")?;
+
+        let applications = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Application>>()
+            .await?;
+        event!(Level::INFO, "received applications");
+        Ok(applications)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, app_name: &str) -> Result<Application> {
+        let url = client.url.join(&format!("applications/{}", app_name))?;
+
+        let application = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Application>()
+            .await?;
+        event!(Level::INFO, "received application {}", application.name);
+        Ok(application)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn subscribe(client: &Client, app_name: &str, event_sources: Vec<EventSource>) -> Result<Application> {
+        let url = Application::subscription_url(client, app_name, &event_sources)?;
+
+        let application = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Application>()
+            .await?;
+        event!(
+            Level::INFO,
+            "subscribed application {} to {} event sources",
+            app_name,
+            event_sources.len()
+        );
+        Ok(application)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn unsubscribe(client: &Client, app_name: &str, event_sources: Vec<EventSource>) -> Result<Application> {
+        let url = Application::subscription_url(client, app_name, &event_sources)?;
+
+        let application = client
+            .delete(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Application>()
+            .await?;
+        event!(
+            Level::INFO,
+            "unsubscribed application {} from {} event sources",
+            app_name,
+            event_sources.len()
+        );
+        Ok(application)
+    }
+
+    /// Restrict the event types sent to the application, empty lists remove the corresponding filter.
+    #[instrument(level = "debug")]
+    pub async fn filter_events(client: &Client, app_name: &str, allowed: Vec<&str>, disallowed: Vec<&str>) -> Result<Application> {
+        let url = client.url.join(&format!("applications/{}/eventFilter", app_name))?;
+
+        let body = Self::event_filter_body(allowed, disallowed);
+
+        let application = client
+            .put(url)
+            .json(&body)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Application>()
+            .await?;
+        event!(Level::INFO, "updated event filter of application {}", app_name);
+        Ok(application)
+    }
+
+    fn event_filter_body(allowed: Vec<&str>, disallowed: Vec<&str>) -> serde_json::Value {
+        let to_filters = |event_types: Vec<&str>| {
+            event_types
+                .into_iter()
+                .map(|event_type| EventFilter {
+                    event_type: event_type.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        json!({
+            "allowed": to_filters(allowed),
+            "disallowed": to_filters(disallowed)
+        })
+    }
+
+    fn subscription_url(client: &Client, app_name: &str, event_sources: &[EventSource]) -> Result<url::Url> {
+        let event_sources = event_sources.iter().map(|source| source.to_string()).collect::<Vec<_>>().join(",");
+
+        Ok(client
+            .url
+            .join(&format!("applications/{}/subscription", app_name))?
+            .query_pairs_mut()
+            .append_pair("eventSource", &event_sources)
+            .finish()
+            .to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_filter_body_is_not_wrapped() {
+        let body = Application::event_filter_body(vec!["StasisStart", "StasisEnd"], vec![]);
+
+        assert_eq!(
+            body,
+            json!({
+                "allowed": [{"type": "StasisStart"}, {"type": "StasisEnd"}],
+                "disallowed": []
+            })
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use chrono::DateTime;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::{event, instrument, Level};
+
+use crate::{client::Client, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct AsteriskInfo {
+    pub build: Option<BuildInfo>,
+    pub system: Option<SystemInfo>,
+    pub config: Option<ConfigInfo>,
+    pub status: Option<StatusInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InfoSection {
+    #[display("build")]
+    Build,
+    #[display("system")]
+    System,
+    #[display("config")]
+    Config,
+    #[display("status")]
+    Status,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BuildInfo {
+    pub os: String,
+    pub kernel: String,
+    pub options: String,
+    pub machine: String,
+    pub date: String,
+    pub user: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct SystemInfo {
+    pub version: String,
+    pub entity_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigInfo {
+    pub name: String,
+    pub default_language: String,
+    pub max_channels: Option<i64>,
+    pub max_open_files: Option<i64>,
+    pub max_load: Option<f64>,
+    pub setid: SetId,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct SetId {
+    pub user: String,
+    pub group: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct StatusInfo {
+    pub startup_time: DateTime<chrono::Utc>,
+    pub last_reload_time: DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct AsteriskPing {
+    pub asterisk_id: String,
+    pub ping: String,
+    pub timestamp: DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Module {
+    pub name: String,
+    pub description: String,
+    pub use_count: i64,
+    pub status: String,
+    pub support_level: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct LogChannel {
+    pub channel: String,
+    #[serde(rename = "type")]
+    pub log_type: String,
+    pub status: String,
+    pub configuration: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigTuple {
+    pub attribute: String,
+    pub value: String,
+}
+
+impl AsteriskInfo {
+    /// Retrieve information about Asterisk, restricted to the `only` sections when not empty.
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, only: Vec<InfoSection>) -> Result<AsteriskInfo> {
+        let mut url = client.url.join("asterisk/info")?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if !only.is_empty() {
+                let only = only.iter().map(|section| section.to_string()).collect::<Vec<_>>().join(",");
+                query.append_pair("only", &only);
+            }
+        }
+
+        let info = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<AsteriskInfo>()
+            .await?;
+        event!(Level::INFO, "received asterisk info");
+        Ok(info)
+    }
+}
+
+impl AsteriskPing {
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client) -> Result<AsteriskPing> {
+        let url = client.url.join("asterisk/ping")?;
+
+        let ping = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<AsteriskPing>()
+            .await?;
+        event!(Level::INFO, "received ping from {}", ping.asterisk_id);
+        Ok(ping)
+    }
+}
+
+impl Module {
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client) -> Result<Vec<Module>> {
+        let url = client.url.join("asterisk/modules")?;
+
+        let modules = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Module>>()
+            .await?;
+        event!(Level::INFO, "received modules");
+        Ok(modules)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, module_name: &str) -> Result<Module> {
+        let url = client.url.join(&format!("asterisk/modules/{}", module_name))?;
+
+        let module = client.get(url).send().await?.ari_error_for_status().await?.json::<Module>().await?;
+        event!(Level::INFO, "received module {}", module.name);
+        Ok(module)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn load(client: &Client, module_name: &str) -> Result<()> {
+        let url = client.url.join(&format!("asterisk/modules/{}", module_name))?;
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "loaded module {}", module_name);
+        Ok(())
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn unload(client: &Client, module_name: &str) -> Result<()> {
+        let url = client.url.join(&format!("asterisk/modules/{}", module_name))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "unloaded module {}", module_name);
+        Ok(())
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn reload(client: &Client, module_name: &str) -> Result<()> {
+        let url = client.url.join(&format!("asterisk/modules/{}", module_name))?;
+
+        client.put(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "reloaded module {}", module_name);
+        Ok(())
+    }
+}
+
+impl LogChannel {
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client) -> Result<Vec<LogChannel>> {
+        let url = client.url.join("asterisk/logging")?;
+
+        let log_channels = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<LogChannel>>()
+            .await?;
+        event!(Level::INFO, "received log channels");
+        Ok(log_channels)
+    }
+
+    /// Create a log channel, `configuration` lists the levels to log such as `notice,warning,error`.
+    #[instrument(level = "debug")]
+    pub async fn add(client: &Client, log_channel_name: &str, configuration: &str) -> Result<()> {
+        let url = client
+            .url
+            .join(&format!("asterisk/logging/{}", log_channel_name))?
+            .query_pairs_mut()
+            .append_pair("configuration", configuration)
+            .finish()
+            .to_owned();
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "added log channel {}", log_channel_name);
+        Ok(())
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn remove(client: &Client, log_channel_name: &str) -> Result<()> {
+        let url = client.url.join(&format!("asterisk/logging/{}", log_channel_name))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "removed log channel {}", log_channel_name);
+        Ok(())
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn rotate(client: &Client, log_channel_name: &str) -> Result<()> {
+        let url = client.url.join(&format!("asterisk/logging/{}/rotate", log_channel_name))?;
+
+        client.put(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "rotated log channel {}", log_channel_name);
+        Ok(())
+    }
+}
+
+impl ConfigTuple {
+    /// Retrieve a dynamic configuration object, e.g. `res_pjsip`, `endpoint`, `alice`.
+    #[instrument(level = "debug")]
+    pub async fn get_object(client: &Client, config_class: &str, object_type: &str, id: &str) -> Result<Vec<ConfigTuple>> {
+        let url = client
+            .url
+            .join(&format!("asterisk/config/dynamic/{}/{}/{}", config_class, object_type, id))?;
+
+        let fields = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<ConfigTuple>>()
+            .await?;
+        event!(Level::INFO, "received {} {}/{}", config_class, object_type, id);
+        Ok(fields)
+    }
+
+    /// Create or update a dynamic configuration object, returning its resulting fields.
+    #[instrument(level = "debug")]
+    pub async fn update_object(
+        client: &Client,
+        config_class: &str,
+        object_type: &str,
+        id: &str,
+        fields: Vec<ConfigTuple>,
+    ) -> Result<Vec<ConfigTuple>> {
+        let url = client
+            .url
+            .join(&format!("asterisk/config/dynamic/{}/{}/{}", config_class, object_type, id))?;
+
+        let body = json!({
+            "fields": fields
+        });
+
+        let fields = client
+            .put(url)
+            .json(&body)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<ConfigTuple>>()
+            .await?;
+        event!(Level::INFO, "updated {} {}/{}", config_class, object_type, id);
+        Ok(fields)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn delete_object(client: &Client, config_class: &str, object_type: &str, id: &str) -> Result<()> {
+        let url = client
+            .url
+            .join(&format!("asterisk/config/dynamic/{}/{}/{}", config_class, object_type, id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "deleted {} {}/{}", config_class, object_type, id);
+        Ok(())
+    }
+}
@@ -1,81 +1,475 @@
+use chrono::DateTime;
+use derive_more::Display;
 use serde::{Deserialize, Serialize};
-use tracing::instrument;
+use tracing::{event, instrument, Level};
+use url::Url;
 
-use crate::{client::Client, playback::Playback, recording::LiveRecording, Result};
+use crate::{
+    channel::{Channel, RecordingAction, RecordingTermination},
+    client::Client,
+    playback::Playback,
+    recording::LiveRecording,
+    ResponseExt, Result,
+};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
 pub struct Bridge {
     pub id: String,
+    pub technology: String,
+    pub bridge_type: BridgeType,
+    pub bridge_class: String,
+    pub creator: String,
+    pub name: String,
+    pub channels: Vec<String>,
+    pub video_mode: Option<VideoMode>,
+    pub video_source_id: Option<String>,
+    pub creationtime: Option<DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeType {
+    #[display("mixing")]
+    Mixing,
+    #[display("holding")]
+    Holding,
+    #[display("dtmf_events")]
+    DtmfEvents,
+    #[display("proxy_media")]
+    ProxyMedia,
+    #[display("video_sfu")]
+    VideoSfu,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoMode {
+    #[display("none")]
+    None,
+    #[display("talker")]
+    Talker,
+    #[display("single")]
+    Single,
+    #[display("sfu")]
+    Sfu,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BridgeCreated {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub bridge: Bridge,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BridgeDestroyed {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub bridge: Bridge,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BridgeMerged {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub bridge: Bridge,
+    pub bridge_from: Bridge,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BridgeVideoSourceChanged {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub bridge: Bridge,
+    pub old_video_source_id: Option<String>,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BridgeBlindTransfer {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel: Channel,
+    pub replace_channel: Option<Channel>,
+    pub transferee: Option<Channel>,
+    pub exten: String,
+    pub context: String,
+    pub result: String,
+    pub is_external: bool,
+    pub bridge: Option<Bridge>,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct BridgeAttendedTransfer {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub transferer_first_leg: Channel,
+    pub transferer_second_leg: Channel,
+    pub replace_channel: Option<Channel>,
+    pub transferee: Option<Channel>,
+    pub transfer_target: Option<Channel>,
+    pub result: String,
+    pub is_external: bool,
+    pub transferer_first_leg_bridge: Option<Bridge>,
+    pub transferer_second_leg_bridge: Option<Bridge>,
+    pub destination_type: String,
+    pub destination_bridge: Option<String>,
+    pub destination_application: Option<String>,
+    pub destination_link_first_leg: Option<Channel>,
+    pub destination_link_second_leg: Option<Channel>,
+    pub destination_threeway_channel: Option<Channel>,
+    pub destination_threeway_bridge: Option<Bridge>,
+    pub asterisk_id: String,
+    pub application: String,
 }
 
 impl Bridge {
     #[instrument(level = "debug")]
-    pub async fn destroy(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn destroy(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("bridges/{}", self.id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "destroyed bridge with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn add_channel(&self, _client: &Client, _channel_id: &str) -> Result<()> {
-        unimplemented!()
+    pub async fn add_channel(&self, client: &Client, channel_id: &str, role: Option<&str>, absorb_dtmf: bool, mute: bool) -> Result<()> {
+        let mut url = client.url.join(&format!("bridges/{}/addChannel", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            query
+                .append_pair("channel", channel_id)
+                .append_pair("absorbDTMF", &absorb_dtmf.to_string())
+                .append_pair("mute", &mute.to_string());
+
+            if let Some(role) = role {
+                query.append_pair("role", role);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+
+        event!(Level::INFO, "added channel with id {} to bridge with id {}", channel_id, self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn remove_channel(&self, _client: &Client, _channel_id: &str) -> Result<()> {
-        unimplemented!()
+    pub async fn remove_channel(&self, client: &Client, channel_id: &str) -> Result<()> {
+        let url = client
+            .url
+            .join(&format!("bridges/{}/removeChannel", self.id))?
+            .query_pairs_mut()
+            .append_pair("channel", channel_id)
+            .finish()
+            .to_owned();
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(
+            Level::INFO,
+            "removed channel with id {} from bridge with id {}",
+            channel_id,
+            self.id
+        );
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn set_channel_as_video_source(&self, _client: &Client, _channel_id: &str, _video_source_id: &str) -> Result<()> {
-        unimplemented!()
+    pub async fn set_channel_as_video_source(&self, client: &Client, channel_id: &str) -> Result<()> {
+        let url = client.url.join(&format!("bridges/{}/videoSource/{}", self.id, channel_id))?;
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(
+            Level::INFO,
+            "set channel with id {} as video source of bridge with id {}",
+            channel_id,
+            self.id
+        );
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn unset_video_source(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn unset_video_source(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("bridges/{}/videoSource", self.id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "unset video source of bridge with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn start_moh(&self, _client: &Client, _moh_class: &str) -> Result<()> {
-        unimplemented!()
+    pub async fn start_moh(&self, client: &Client, moh_class: Option<&str>) -> Result<()> {
+        let mut url = client.url.join(&format!("bridges/{}/moh", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if let Some(moh_class) = moh_class {
+                query.append_pair("mohClass", moh_class);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+
+        event!(Level::INFO, "started music on hold on bridge with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn stop_moh(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn stop_moh(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("bridges/{}/moh", self.id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "stopped music on hold on bridge with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn play_media(&self, _client: &Client, _playback: &Playback) -> Result<()> {
-        unimplemented!()
+    pub async fn play_media(
+        &self,
+        client: &Client,
+        media: Vec<&str>,
+        lang: Option<&str>,
+        offset_ms: Option<u32>,
+        skip_ms: Option<u32>,
+        playback_id: Option<&str>,
+    ) -> Result<Playback> {
+        let mut url = client.url.join(&format!("bridges/{}/play", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("media", &media.join(","));
+
+            if let Some(lang) = lang {
+                query.append_pair("lang", lang);
+            }
+
+            if let Some(offset_ms) = offset_ms {
+                query.append_pair("offsetms", &offset_ms.to_string());
+            }
+
+            if let Some(skip_ms) = skip_ms {
+                query.append_pair("skipms", &skip_ms.to_string());
+            }
+
+            if let Some(playback_id) = playback_id {
+                query.append_pair("playbackId", playback_id);
+            }
+        }
+
+        let playback = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Playback>()
+            .await?;
+
+        event!(
+            Level::INFO,
+            "started media playback with id {} on bridge with id {}",
+            playback.id,
+            self.id
+        );
+
+        Ok(playback)
     }
 
     #[instrument(level = "debug")]
-    pub async fn stop_media(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn play_media_with_id(
+        &self,
+        client: &Client,
+        playback_id: &str,
+        media: Vec<&str>,
+        lang: Option<&str>,
+        offset_ms: Option<u32>,
+        skip_ms: Option<u32>,
+    ) -> Result<Playback> {
+        let mut url = client.url.join(&format!("bridges/{}/play/{}", self.id, playback_id))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("media", &media.join(","));
+
+            if let Some(lang) = lang {
+                query.append_pair("lang", lang);
+            }
+
+            if let Some(offset_ms) = offset_ms {
+                query.append_pair("offsetms", &offset_ms.to_string());
+            }
+
+            if let Some(skip_ms) = skip_ms {
+                query.append_pair("skipms", &skip_ms.to_string());
+            }
+        }
+
+        let playback = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Playback>()
+            .await?;
+
+        event!(
+            Level::INFO,
+            "started media playback with id {} on bridge with id {}",
+            playback.id,
+            self.id
+        );
+
+        Ok(playback)
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[instrument(level = "debug")]
-    pub async fn start_recording(&self, _client: &Client, _recording: &LiveRecording) -> Result<()> {
-        unimplemented!()
+    pub async fn start_recording(
+        &self,
+        client: &Client,
+        name: &str,
+        format: &str,
+        max_duration_seconds: Option<u32>,
+        max_silence_seconds: Option<u32>,
+        if_exists: RecordingAction,
+        beep: bool,
+        terminate_on: RecordingTermination,
+    ) -> Result<LiveRecording> {
+        let mut url = client.url.join(&format!("bridges/{}/record", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            query
+                .append_pair("name", name)
+                .append_pair("format", format)
+                .append_pair("ifExists", &format!("{}", if_exists))
+                .append_pair("beep", &beep.to_string())
+                .append_pair("terminateOn", &format!("{}", terminate_on));
+
+            if let Some(max_duration_seconds) = max_duration_seconds {
+                query.append_pair("maxDurationSeconds", &max_duration_seconds.to_string());
+            }
+
+            if let Some(max_silence_seconds) = max_silence_seconds {
+                query.append_pair("maxSilenceSeconds", &max_silence_seconds.to_string());
+            }
+        }
+
+        let recording = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<LiveRecording>()
+            .await?;
+
+        event!(
+            Level::INFO,
+            "started recording with name {} on bridge with id {}",
+            recording.name,
+            self.id
+        );
+
+        Ok(recording)
     }
 
     #[instrument(level = "debug")]
-    pub async fn list_bridges(_client: &Client) -> Result<Vec<Bridge>> {
-        unimplemented!()
+    pub async fn list_bridges(client: &Client) -> Result<Vec<Bridge>> {
+        let url: Url = client.url.join("bridges")?;
+
+        let bridges = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Bridge>>()
+            .await?;
+        event!(Level::INFO, "received bridges");
+        Ok(bridges)
     }
 
     #[instrument(level = "debug")]
-    pub async fn create_bridge(_client: &Client, _bridge_id: &str) -> Result<Bridge> {
-        unimplemented!()
+    pub async fn create_bridge(client: &Client, bridge_type: Vec<BridgeType>, name: Option<&str>) -> Result<Bridge> {
+        let mut url = client.url.join("bridges")?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if !bridge_type.is_empty() {
+                let bridge_type = bridge_type.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(",");
+                query.append_pair("type", &bridge_type);
+            }
+
+            if let Some(name) = name {
+                query.append_pair("name", name);
+            }
+        }
+
+        let bridge = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Bridge>()
+            .await?;
+
+        event!(Level::INFO, "created bridge with id {}", bridge.id);
+        Ok(bridge)
     }
 
+    /// Create a new bridge with the given id, or update the bridge if it already exists.
     #[instrument(level = "debug")]
-    pub async fn create_bridge_with_id(_client: &Client, _bridge_id: &str, _bridge: &Bridge) -> Result<Bridge> {
-        unimplemented!()
+    pub async fn create_bridge_with_id(
+        client: &Client,
+        bridge_id: &str,
+        bridge_type: Vec<BridgeType>,
+        name: Option<&str>,
+    ) -> Result<Bridge> {
+        let mut url = client.url.join(&format!("bridges/{}", bridge_id))?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if !bridge_type.is_empty() {
+                let bridge_type = bridge_type.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(",");
+                query.append_pair("type", &bridge_type);
+            }
+
+            if let Some(name) = name {
+                query.append_pair("name", name);
+            }
+        }
+
+        let bridge = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Bridge>()
+            .await?;
+
+        event!(Level::INFO, "created bridge with id {}", bridge.id);
+        Ok(bridge)
     }
 
     #[instrument(level = "debug")]
-    pub async fn get_bridge(_client: &Client, _bridge_id: &str) -> Result<Bridge> {
-        unimplemented!()
+    pub async fn get_bridge(client: &Client, bridge_id: &str) -> Result<Bridge> {
+        let url = client.url.join(&format!("bridges/{}", bridge_id))?;
+
+        let bridge = client.get(url).send().await?.ari_error_for_status().await?.json::<Bridge>().await?;
+        event!(Level::INFO, "received bridge with id {}", bridge.id);
+        Ok(bridge)
     }
 }
@@ -2,26 +2,72 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, Duration};
 use derive_more::Display;
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tokio::time::timeout;
 use tracing::{event, instrument, Level};
-use url::Url;
-
-use crate::{client::Client, playback::Playback, recording::LiveRecording, rtp_statistics::RtpStatistics, variable::Variable, Result};
-
-#[derive(Serialize, Deserialize, Debug)]
+use url::{form_urlencoded, Url, UrlQuery};
+
+use crate::{
+    bridge::{Bridge, BridgeType},
+    client::Client,
+    endpoint::Endpoint,
+    external_media::{ConnectionType, Encapsulation, Transport},
+    playback::Playback,
+    recording::LiveRecording,
+    rtp_statistics::RtpStatistics,
+    variable::Variable,
+    AriError, Event, ResponseExt, Result,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct Channel {
     pub id: String,
     pub name: String,
-    pub state: String,
-    pub protocol_id: String,
+    pub state: ChannelState,
+    /// Protocol specific identifier such as the SIP Call-ID, absent before Asterisk 17.
+    pub protocol_id: Option<String>,
     pub caller: Caller,
     pub connected: Caller,
     pub accountcode: String,
     pub dialplan: Dialplan,
-    pub creationtime: String,
+    pub creationtime: DateTime<chrono::Utc>,
     pub language: String,
+    /// Variables listed in the `channelvars` setting of `ari.conf`.
+    pub channelvars: Option<HashMap<String, String>>,
+    pub caller_rdnis: Option<String>,
+    pub tenantid: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelState {
+    #[display("Down")]
+    Down,
+    #[display("Rsrvd")]
+    Rsrvd,
+    #[display("OffHook")]
+    OffHook,
+    #[display("Dialing")]
+    Dialing,
+    #[display("Ring")]
+    Ring,
+    #[display("Ringing")]
+    Ringing,
+    #[display("Up")]
+    Up,
+    #[display("Busy")]
+    Busy,
+    #[serde(rename = "Dialing Offhook")]
+    #[display("Dialing Offhook")]
+    DialingOffhook,
+    #[serde(rename = "Pre-ring")]
+    #[display("Pre-ring")]
+    PreRing,
+    #[serde(other)]
+    #[display("Unknown")]
+    Unknown,
 }
 
 #[derive(Debug)]
@@ -38,6 +84,372 @@ pub enum OriginateParams<'a> {
     },
 }
 
+impl OriginateParams<'_> {
+    fn append_to(&self, query: &mut form_urlencoded::Serializer<UrlQuery>) {
+        match self {
+            OriginateParams::Extension {
+                extension,
+                context,
+                priority,
+                label,
+            } => {
+                query.append_pair("extension", extension);
+
+                if let Some(context) = context {
+                    query.append_pair("context", context);
+                }
+
+                if let Some(priority) = priority {
+                    query.append_pair("priority", &priority.to_string());
+                }
+
+                if let Some(label) = label {
+                    query.append_pair("label", label);
+                }
+            }
+            OriginateParams::Application { app, app_args } => {
+                query.append_pair("app", app);
+
+                if !app_args.is_empty() {
+                    query.append_pair("appArgs", &app_args.join(","));
+                }
+            }
+        }
+    }
+}
+
+/// Parameters shared by [`OriginateRequest`] and [`CreateChannelRequest`].
+#[derive(Debug, Default)]
+struct ChannelOptions<'a> {
+    channel_id: Option<&'a str>,
+    other_channel_id: Option<&'a str>,
+    originator: Option<&'a str>,
+    formats: Vec<&'a str>,
+    variables: HashMap<&'a str, &'a str>,
+}
+
+impl ChannelOptions<'_> {
+    fn append_to(&self, query: &mut form_urlencoded::Serializer<UrlQuery>) {
+        if let Some(channel_id) = self.channel_id {
+            query.append_pair("channelId", channel_id);
+        }
+
+        if let Some(other_channel_id) = self.other_channel_id {
+            query.append_pair("otherChannelId", other_channel_id);
+        }
+
+        if let Some(originator) = self.originator {
+            query.append_pair("originator", originator);
+        }
+
+        if !self.formats.is_empty() {
+            query.append_pair("formats", &self.formats.join(","));
+        }
+    }
+}
+
+/// Destination of an [`OriginateRequest`] built with [`OriginateRequest::extension`].
+#[derive(Debug)]
+pub struct ToExtension<'a> {
+    extension: &'a str,
+    context: Option<&'a str>,
+    priority: Option<i32>,
+    label: Option<&'a str>,
+}
+
+impl<'a> From<ToExtension<'a>> for OriginateParams<'a> {
+    fn from(destination: ToExtension<'a>) -> Self {
+        OriginateParams::Extension {
+            extension: destination.extension,
+            context: destination.context,
+            priority: destination.priority,
+            label: destination.label,
+        }
+    }
+}
+
+/// Destination of an [`OriginateRequest`] built with [`OriginateRequest::application`], or of a [`CreateChannelRequest`].
+#[derive(Debug)]
+pub struct ToApplication<'a> {
+    app: &'a str,
+    app_args: Vec<&'a str>,
+}
+
+impl<'a> From<ToApplication<'a>> for OriginateParams<'a> {
+    fn from(destination: ToApplication<'a>) -> Self {
+        OriginateParams::Application {
+            app: destination.app,
+            app_args: destination.app_args,
+        }
+    }
+}
+
+/// Request for [`Channel::originate`], the destination is either a dialplan extension or a Stasis application.
+///
+/// The destination is part of the type, so only the setters matching it are available.
+#[derive(Debug)]
+pub struct OriginateRequest<'a, D = OriginateParams<'a>> {
+    endpoint: &'a str,
+    destination: D,
+    caller_id: Option<&'a str>,
+    timeout: Option<u32>,
+    options: ChannelOptions<'a>,
+}
+
+impl<'a> OriginateRequest<'a> {
+    pub fn new(endpoint: &'a str, params: OriginateParams<'a>) -> Self {
+        OriginateRequest::with_destination(endpoint, params)
+    }
+}
+
+impl<'a> OriginateRequest<'a, ToExtension<'a>> {
+    /// Originate to `extension`, in the default context unless [`OriginateRequest::context`] is set.
+    pub fn extension(endpoint: &'a str, extension: &'a str) -> Self {
+        OriginateRequest::with_destination(
+            endpoint,
+            ToExtension {
+                extension,
+                context: None,
+                priority: None,
+                label: None,
+            },
+        )
+    }
+
+    pub fn context(mut self, context: &'a str) -> Self {
+        self.destination.context = Some(context);
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.destination.priority = Some(priority);
+        self
+    }
+
+    /// Priority label to continue at, takes precedence over the priority.
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.destination.label = Some(label);
+        self
+    }
+}
+
+impl<'a> OriginateRequest<'a, ToApplication<'a>> {
+    /// Originate into the Stasis application `app`.
+    pub fn application(endpoint: &'a str, app: &'a str) -> Self {
+        OriginateRequest::with_destination(endpoint, ToApplication { app, app_args: vec![] })
+    }
+
+    pub fn app_args(mut self, app_args: Vec<&'a str>) -> Self {
+        self.destination.app_args = app_args;
+        self
+    }
+}
+
+impl<'a, D: Into<OriginateParams<'a>>> OriginateRequest<'a, D> {
+    fn with_destination(endpoint: &'a str, destination: D) -> Self {
+        OriginateRequest {
+            endpoint,
+            destination,
+            caller_id: None,
+            timeout: None,
+            options: ChannelOptions::default(),
+        }
+    }
+
+    pub fn caller_id(mut self, caller_id: &'a str) -> Self {
+        self.caller_id = Some(caller_id);
+        self
+    }
+
+    /// Seconds to wait for the endpoint to answer, defaults to 30.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn channel_id(mut self, channel_id: &'a str) -> Self {
+        self.options.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Unique id of the second channel when originating a local channel.
+    pub fn other_channel_id(mut self, other_channel_id: &'a str) -> Self {
+        self.options.other_channel_id = Some(other_channel_id);
+        self
+    }
+
+    /// Id of the channel responsible for the call, used for linkedid and codec negotiation.
+    pub fn originator(mut self, originator: &'a str) -> Self {
+        self.options.originator = Some(originator);
+        self
+    }
+
+    /// Formats to offer, e.g. `ulaw`, ignored when an originator is set.
+    pub fn formats(mut self, formats: Vec<&'a str>) -> Self {
+        self.options.formats = formats;
+        self
+    }
+
+    /// Set a channel variable, may be called several times.
+    pub fn variable(mut self, name: &'a str, value: &'a str) -> Self {
+        self.options.variables.insert(name, value);
+        self
+    }
+}
+
+/// Request for [`Channel::create`], the channel is placed in the Stasis application `app` once dialed.
+#[derive(Debug)]
+pub struct CreateChannelRequest<'a> {
+    endpoint: &'a str,
+    destination: ToApplication<'a>,
+    options: ChannelOptions<'a>,
+}
+
+impl<'a> CreateChannelRequest<'a> {
+    pub fn new(endpoint: &'a str, app: &'a str) -> Self {
+        CreateChannelRequest {
+            endpoint,
+            destination: ToApplication { app, app_args: vec![] },
+            options: ChannelOptions::default(),
+        }
+    }
+
+    pub fn app_args(mut self, app_args: Vec<&'a str>) -> Self {
+        self.destination.app_args = app_args;
+        self
+    }
+
+    pub fn channel_id(mut self, channel_id: &'a str) -> Self {
+        self.options.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Unique id of the second channel when creating a local channel.
+    pub fn other_channel_id(mut self, other_channel_id: &'a str) -> Self {
+        self.options.other_channel_id = Some(other_channel_id);
+        self
+    }
+
+    /// Id of the channel responsible for the call, used for linkedid and codec negotiation.
+    pub fn originator(mut self, originator: &'a str) -> Self {
+        self.options.originator = Some(originator);
+        self
+    }
+
+    /// Formats to offer, e.g. `ulaw`, ignored when an originator is set.
+    pub fn formats(mut self, formats: Vec<&'a str>) -> Self {
+        self.options.formats = formats;
+        self
+    }
+
+    /// Set a channel variable, may be called several times.
+    pub fn variable(mut self, name: &'a str, value: &'a str) -> Self {
+        self.options.variables.insert(name, value);
+        self
+    }
+}
+
+/// Request for [`Channel::start_external_media`], media of the channel is exchanged with `external_host`.
+#[derive(Debug)]
+pub struct ExternalMediaRequest<'a> {
+    app: &'a str,
+    external_host: &'a str,
+    format: &'a str,
+    encapsulation: Option<Encapsulation>,
+    transport: Option<Transport>,
+    connection_type: Option<ConnectionType>,
+    direction: Option<Direction>,
+    channel_id: Option<&'a str>,
+    data: Option<&'a str>,
+    variables: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> ExternalMediaRequest<'a> {
+    /// `external_host` is the `host:port` to send media to, `format` the codec to use, e.g. `ulaw`.
+    pub fn new(app: &'a str, external_host: &'a str, format: &'a str) -> Self {
+        ExternalMediaRequest {
+            app,
+            external_host,
+            format,
+            encapsulation: None,
+            transport: None,
+            connection_type: None,
+            direction: None,
+            channel_id: None,
+            data: None,
+            variables: HashMap::new(),
+        }
+    }
+
+    pub fn encapsulation(mut self, encapsulation: Encapsulation) -> Self {
+        self.encapsulation = Some(encapsulation);
+        self
+    }
+
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    pub fn connection_type(mut self, connection_type: ConnectionType) -> Self {
+        self.connection_type = Some(connection_type);
+        self
+    }
+
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn channel_id(mut self, channel_id: &'a str) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Opaque data passed to the external media server, e.g. an AudioSocket UUID.
+    pub fn data(mut self, data: &'a str) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Set a channel variable, may be called several times.
+    pub fn variable(mut self, name: &'a str, value: &'a str) -> Self {
+        self.variables.insert(name, value);
+        self
+    }
+
+    fn append_to(&self, query: &mut form_urlencoded::Serializer<UrlQuery>) {
+        query
+            .append_pair("app", self.app)
+            .append_pair("external_host", self.external_host)
+            .append_pair("format", self.format);
+
+        if let Some(encapsulation) = self.encapsulation {
+            query.append_pair("encapsulation", &encapsulation.to_string());
+        }
+
+        if let Some(transport) = self.transport {
+            query.append_pair("transport", &transport.to_string());
+        }
+
+        if let Some(connection_type) = self.connection_type {
+            query.append_pair("connection_type", &connection_type.to_string());
+        }
+
+        if let Some(direction) = self.direction {
+            query.append_pair("direction", &direction.to_string());
+        }
+
+        if let Some(channel_id) = self.channel_id {
+            query.append_pair("channelId", channel_id);
+        }
+
+        if let Some(data) = self.data {
+            query.append_pair("data", data);
+        }
+    }
+}
+
 #[derive(Debug, Display)]
 pub enum Reason {
     #[display("{}", _0)]
@@ -70,7 +482,7 @@ pub enum Reason {
     AnsweredElsewhere,
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     #[display("in")]
     In,
@@ -80,6 +492,20 @@ pub enum Direction {
     Both,
 }
 
+/// How long [`Channel::supervise`] waits for the snoop channel to enter the application.
+pub const SUPERVISION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How a supervisor joins a call through [`Channel::supervise`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisionMode {
+    /// Hear both parties without being heard.
+    Listen,
+    /// Hear both parties and talk to the supervised channel only.
+    Whisper,
+    /// Hear and talk to both parties.
+    Barge,
+}
+
 #[derive(Debug, Display)]
 pub enum RecordingAction {
     #[display("overwrite")]
@@ -102,7 +528,7 @@ pub enum RecordingTermination {
     Octothorpe,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct StasisStart {
     pub timestamp: DateTime<chrono::Utc>,
@@ -112,7 +538,7 @@ pub struct StasisStart {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct StasisEnd {
     pub timestamp: DateTime<chrono::Utc>,
@@ -121,7 +547,7 @@ pub struct StasisEnd {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelCreated {
     pub timestamp: DateTime<chrono::Utc>,
@@ -130,7 +556,7 @@ pub struct ChannelCreated {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelDestroyed {
     pub timestamp: DateTime<chrono::Utc>,
@@ -141,7 +567,7 @@ pub struct ChannelDestroyed {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelVarset {
     pub timestamp: DateTime<chrono::Utc>,
@@ -152,7 +578,7 @@ pub struct ChannelVarset {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelHangupRequest {
     pub timestamp: DateTime<chrono::Utc>,
@@ -163,7 +589,7 @@ pub struct ChannelHangupRequest {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelDialplan {
     pub timestamp: DateTime<chrono::Utc>,
@@ -174,7 +600,7 @@ pub struct ChannelDialplan {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelStateChange {
     pub timestamp: DateTime<chrono::Utc>,
@@ -183,7 +609,7 @@ pub struct ChannelStateChange {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct ChannelDtmfReceived {
     pub timestamp: DateTime<chrono::Utc>,
@@ -194,20 +620,147 @@ pub struct ChannelDtmfReceived {
     pub application: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelEnteredBridge {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub bridge: Bridge,
+    pub channel: Option<Channel>,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelLeftBridge {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub bridge: Bridge,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelTalkingStarted {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelTalkingFinished {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub duration: i32,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelHold {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub musicclass: Option<String>,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelUnhold {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelCallerId {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub caller_presentation: i32,
+    pub caller_presentation_txt: String,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelConnectedLine {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelUserevent {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub eventname: String,
+    pub userevent: serde_json::Value,
+    pub channel: Option<Channel>,
+    pub bridge: Option<Bridge>,
+    pub endpoint: Option<Endpoint>,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelToneDetected {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel: Channel,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Dial {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub caller: Option<Channel>,
+    pub peer: Channel,
+    pub forward: Option<String>,
+    pub forwarded: Option<Channel>,
+    pub dialstring: Option<String>,
+    pub dialstatus: String,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ApplicationMoveFailed {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel: Channel,
+    pub destination: String,
+    pub args: Vec<String>,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct Caller {
     pub name: String,
     pub number: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct Dialplan {
     pub context: String,
     pub exten: String,
     pub priority: i32,
+    #[serde(default)]
     pub app_name: String,
+    #[serde(default)]
     pub app_data: String,
 }
 
@@ -215,73 +768,118 @@ impl Channel {
     #[instrument(level = "debug")]
     pub async fn hangup(self, client: &Client, reason: Reason) -> Result<()> {
         let mut url = client.url.join(&format!("channels/{}", self.id))?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
+        {
+            let mut query = url.query_pairs_mut();
 
-        match reason {
-            Reason::Code(_) => url.append_pair("reason_code", &format!("{}", reason)),
-            _ => url.append_pair("reason", &format!("{}", reason)),
-        };
+            match reason {
+                Reason::Code(_) => query.append_pair("reason_code", &format!("{}", reason)),
+                _ => query.append_pair("reason", &format!("{}", reason)),
+            };
+        }
 
-        reqwest::Client::new().delete(url.finish().to_owned()).send().await?;
+        client.delete(url).send().await?.ari_error_for_status().await?;
 
         event!(Level::INFO, "hung up channel with id {}", self.id);
         Ok(())
     }
 
+    /// Exit the application and continue in the dialplan, at the current location for every `None` parameter.
+    ///
+    /// `label` takes precedence over `priority` when both are given.
     #[instrument(level = "debug")]
-    pub fn continue_in_dialplan(self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn continue_in_dialplan(
+        &self,
+        client: &Client,
+        context: Option<&str>,
+        extension: Option<&str>,
+        priority: Option<i32>,
+        label: Option<&str>,
+    ) -> Result<()> {
+        let mut url = client.url.join(&format!("channels/{}/continue", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if let Some(context) = context {
+                query.append_pair("context", context);
+            }
+
+            if let Some(extension) = extension {
+                query.append_pair("extension", extension);
+            }
+
+            if let Some(priority) = priority {
+                query.append_pair("priority", &priority.to_string());
+            }
+
+            if let Some(label) = label {
+                query.append_pair("label", label);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "continued channel with id {} in dialplan", self.id);
+        Ok(())
     }
 
     /// Transfer the channel to another ARI application.
-    /// Same as `move` in Asterisk
+    /// Same as `move` in Asterisk, an `ApplicationMoveFailed` event is emitted if the application is not registered.
     #[instrument(level = "debug")]
-    pub fn transfer(self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn transfer(&self, client: &Client, app: &str, app_args: Vec<&str>) -> Result<()> {
+        let mut url = client.url.join(&format!("channels/{}/move", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("app", app);
+
+            if !app_args.is_empty() {
+                let app_args = app_args.join(",");
+                query.append_pair("appArgs", &app_args);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "moved channel with id {} to application {}", self.id, app);
+        Ok(())
     }
 
+    /// Redirect the channel to `endpoint`, e.g. `PJSIP/bob`, using a technology specific mechanism such as a SIP 302.
     #[instrument(level = "debug")]
-    pub async fn answer(&self, client: &Client) -> Result<()> {
+    pub async fn redirect(&self, client: &Client, endpoint: &str) -> Result<()> {
         let url = client
             .url
-            .join(&format!("channels/{}/answer", self.id))?
+            .join(&format!("channels/{}/redirect", self.id))?
             .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
+            .append_pair("endpoint", endpoint)
             .finish()
             .to_owned();
 
-        reqwest::Client::new().post(url).send().await?;
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "redirected channel with id {} to {}", self.id, endpoint);
+        Ok(())
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn answer(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("channels/{}/answer", self.id))?;
+
+        client.post(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "answered channel with id {}", self.id);
         Ok(())
     }
 
     #[instrument(level = "debug")]
     pub async fn start_ringing(&self, client: &Client) -> Result<()> {
-        let url = client
-            .url
-            .join(&format!("channels/{}/ring", self.id))?
-            .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
-            .finish()
-            .to_owned();
+        let url = client.url.join(&format!("channels/{}/ring", self.id))?;
 
-        reqwest::Client::new().post(url).send().await?;
+        client.post(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "started ringing channel with id {}", self.id);
         Ok(())
     }
 
     #[instrument(level = "debug")]
     pub async fn stop_ringing(&self, client: &Client) -> Result<()> {
-        let url = client
-            .url
-            .join(&format!("channels/{}/ring", self.id))?
-            .query_pairs_mut()
-            .append_pair("api_key", &format!("{}:{}", client.username, client.password))
-            .finish()
-            .to_owned();
+        let url = client.url.join(&format!("channels/{}/ring", self.id))?;
 
-        reqwest::Client::new().delete(url).send().await?;
+        client.delete(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "stopped ringing channel with id {}", self.id);
         Ok(())
     }
@@ -297,22 +895,24 @@ impl Channel {
         after: Option<Duration>,
     ) -> Result<()> {
         let mut url = client.url.join(&format!("channels/{}/dtmf", self.id))?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
+        {
+            let mut query = url.query_pairs_mut();
 
-        url.append_pair("dtmf", dtmf)
-            .append_pair("between", &between.map(|d| d.num_milliseconds()).unwrap_or(100).to_string())
-            .append_pair("duration", &duration.map(|d| d.num_milliseconds()).unwrap_or(100).to_string());
+            query
+                .append_pair("dtmf", dtmf)
+                .append_pair("between", &between.map(|d| d.num_milliseconds()).unwrap_or(100).to_string())
+                .append_pair("duration", &duration.map(|d| d.num_milliseconds()).unwrap_or(100).to_string());
 
-        if let Some(before) = before {
-            url.append_pair("before", &before.num_milliseconds().to_string());
-        }
+            if let Some(before) = before {
+                query.append_pair("before", &before.num_milliseconds().to_string());
+            }
 
-        if let Some(after) = after {
-            url.append_pair("after", &after.num_milliseconds().to_string());
+            if let Some(after) = after {
+                query.append_pair("after", &after.num_milliseconds().to_string());
+            }
         }
 
-        reqwest::Client::new().post(url.finish().to_owned()).send().await?;
+        client.post(url).send().await?.ari_error_for_status().await?;
 
         event!(Level::INFO, "sent dtmf '{}' to channel with id {}", dtmf, self.id);
 
@@ -325,12 +925,11 @@ impl Channel {
             .url
             .join(&format!("channels/{}/mute", self.id))?
             .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
             .append_pair("direction", &format!("{}", direction))
             .finish()
             .to_owned();
 
-        reqwest::Client::new().post(url).send().await?;
+        client.post(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "muted channel with id {}", self.id);
         Ok(())
     }
@@ -341,64 +940,75 @@ impl Channel {
             .url
             .join(&format!("channels/{}/mute", self.id))?
             .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
             .append_pair("direction", &format!("{}", direction))
             .finish()
             .to_owned();
 
-        reqwest::Client::new().delete(url).send().await?;
+        client.delete(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "unmuted channel with id {}", self.id);
         Ok(())
     }
 
     #[instrument(level = "debug")]
     pub async fn hold(&self, client: &Client) -> Result<()> {
-        let url = client
-            .url
-            .join(&format!("channels/{}/hold", self.id))?
-            .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
-            .finish()
-            .to_owned();
+        let url = client.url.join(&format!("channels/{}/hold", self.id))?;
 
-        reqwest::Client::new().post(url).send().await?;
+        client.post(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "started hold on channel with id {}", self.id);
         Ok(())
     }
 
     #[instrument(level = "debug")]
     pub async fn unhold(&self, client: &Client) -> Result<()> {
-        let url = client
-            .url
-            .join(&format!("channels/{}/hold", self.id))?
-            .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
-            .finish()
-            .to_owned();
+        let url = client.url.join(&format!("channels/{}/hold", self.id))?;
 
-        reqwest::Client::new().delete(url).send().await?;
+        client.delete(url).send().await?.ari_error_for_status().await?;
         event!(Level::INFO, "stopped hold on channel with id {}", self.id);
         Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub fn start_moh(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn start_moh(&self, client: &Client, moh_class: Option<&str>) -> Result<()> {
+        let mut url = client.url.join(&format!("channels/{}/moh", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if let Some(moh_class) = moh_class {
+                query.append_pair("mohClass", moh_class);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+
+        event!(Level::INFO, "started music on hold on channel with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub fn stop_moh(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn stop_moh(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("channels/{}/moh", self.id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "stopped music on hold on channel with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub fn start_silence(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn start_silence(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("channels/{}/silence", self.id))?;
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "started silence on channel with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub fn stop_silence(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn stop_silence(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("channels/{}/silence", self.id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "stopped silence on channel with id {}", self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
@@ -412,30 +1022,33 @@ impl Channel {
         playback_id: Option<&str>,
     ) -> Result<Playback> {
         let mut url = client.url.join(&format!("channels/{}/play", self.id))?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
-        url.append_pair("media", media);
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("media", media);
 
-        if let Some(lang) = lang {
-            url.append_pair("lang", lang);
-        }
+            if let Some(lang) = lang {
+                query.append_pair("lang", lang);
+            }
 
-        if let Some(offset_ms) = offset_ms {
-            url.append_pair("offset_ms", &offset_ms.to_string());
-        }
+            if let Some(offset_ms) = offset_ms {
+                query.append_pair("offsetms", &offset_ms.to_string());
+            }
 
-        if let Some(skip_ms) = skip_ms {
-            url.append_pair("skip_ms", &skip_ms.to_string());
-        }
+            if let Some(skip_ms) = skip_ms {
+                query.append_pair("skipms", &skip_ms.to_string());
+            }
 
-        if let Some(playback_id) = playback_id {
-            url.append_pair("playback_id", playback_id);
+            if let Some(playback_id) = playback_id {
+                query.append_pair("playbackId", playback_id);
+            }
         }
 
-        let playback = reqwest::Client::new()
-            .post(url.finish().to_owned())
+        let playback = client
+            .post(url)
             .send()
             .await?
+            .ari_error_for_status()
+            .await?
             .json::<Playback>()
             .await?;
 
@@ -459,29 +1072,32 @@ impl Channel {
         offset_ms: Option<u32>,
         skip_ms: Option<u32>,
     ) -> Result<Playback> {
-        let mut url = client.url.join(&format!("channels/{}/play/{}/media", self.id, playback_id))?;
+        let mut url = client.url.join(&format!("channels/{}/play/{}", self.id, playback_id))?;
 
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
-        let media = media.join(",");
-        url.append_pair("media", &media);
+        {
+            let mut query = url.query_pairs_mut();
+            let media = media.join(",");
+            query.append_pair("media", &media);
 
-        if let Some(lang) = lang {
-            url.append_pair("lang", lang);
-        }
+            if let Some(lang) = lang {
+                query.append_pair("lang", lang);
+            }
 
-        if let Some(offset_ms) = offset_ms {
-            url.append_pair("offset_ms", &offset_ms.to_string());
-        }
+            if let Some(offset_ms) = offset_ms {
+                query.append_pair("offsetms", &offset_ms.to_string());
+            }
 
-        if let Some(skip_ms) = skip_ms {
-            url.append_pair("skip_ms", &skip_ms.to_string());
+            if let Some(skip_ms) = skip_ms {
+                query.append_pair("skipms", &skip_ms.to_string());
+            }
         }
 
-        let playback = reqwest::Client::new()
-            .post(url.finish().to_owned())
+        let playback = client
+            .post(url)
             .send()
             .await?
+            .ari_error_for_status()
+            .await?
             .json::<Playback>()
             .await?;
 
@@ -509,34 +1125,38 @@ impl Channel {
         terminate_on: RecordingTermination,
     ) -> Result<LiveRecording> {
         let mut url = client.url.join(&format!("channels/{}/record", self.id))?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
-
-        url.append_pair("name", name)
-            .append_pair("format", format)
-            .append_pair("if_exists", &format!("{}", if_exists))
-            .append_pair("beep", &beep.to_string())
-            .append_pair("terminate_on", &format!("{}", terminate_on));
-
-        if let Some(max_duration_seconds) = max_duration_seconds {
-            url.append_pair("max_duration_seconds", &max_duration_seconds.to_string());
-        }
+        {
+            let mut query = url.query_pairs_mut();
+
+            query
+                .append_pair("name", name)
+                .append_pair("format", format)
+                .append_pair("ifExists", &format!("{}", if_exists))
+                .append_pair("beep", &beep.to_string())
+                .append_pair("terminateOn", &format!("{}", terminate_on));
+
+            if let Some(max_duration_seconds) = max_duration_seconds {
+                query.append_pair("maxDurationSeconds", &max_duration_seconds.to_string());
+            }
 
-        if let Some(max_silence_seconds) = max_silence_seconds {
-            url.append_pair("max_silence_seconds", &max_silence_seconds.to_string());
+            if let Some(max_silence_seconds) = max_silence_seconds {
+                query.append_pair("maxSilenceSeconds", &max_silence_seconds.to_string());
+            }
         }
 
-        let recording = reqwest::Client::new()
-            .post(url.finish().to_owned())
+        let recording = client
+            .post(url)
             .send()
             .await?
+            .ari_error_for_status()
+            .await?
             .json::<LiveRecording>()
             .await?;
 
         event!(
             Level::INFO,
-            "started recording with id {} on channel with id {}",
-            recording.id,
+            "started recording with name {} on channel with id {}",
+            recording.name,
             self.id
         );
 
@@ -544,312 +1164,555 @@ impl Channel {
     }
 
     #[instrument(level = "debug")]
-    pub fn get_variable(&self, _client: &Client) -> Result<Variable> {
-        unimplemented!()
+    pub async fn get_variable(&self, client: &Client, variable: &str) -> Result<Variable> {
+        let url = client
+            .url
+            .join(&format!("channels/{}/variable", self.id))?
+            .query_pairs_mut()
+            .append_pair("variable", variable)
+            .finish()
+            .to_owned();
+
+        let value = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await
+            .map_err(|e| Variable::not_found(e, variable))?
+            .json::<Variable>()
+            .await?;
+
+        event!(Level::INFO, "received variable {} of channel with id {}", variable, self.id);
+        Ok(value)
     }
 
+    /// Set a channel variable, or unset it when `value` is `None`.
     #[instrument(level = "debug")]
-    pub fn set_variable(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn set_variable(&self, client: &Client, variable: &str, value: Option<&str>) -> Result<()> {
+        let mut url = client.url.join(&format!("channels/{}/variable", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("variable", variable);
+
+            if let Some(value) = value {
+                query.append_pair("value", value);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+
+        event!(Level::INFO, "set variable {} on channel with id {}", variable, self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
     pub async fn dial(&self, client: &Client, caller_id: Option<&str>, timeout: Option<u32>) -> Result<()> {
         let mut url = client.url.join(&format!("channels/{}/dial", self.id))?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
+        {
+            let mut query = url.query_pairs_mut();
 
-        if let Some(caller_id) = caller_id {
-            url.append_pair("callerId", caller_id);
-        }
+            if let Some(caller_id) = caller_id {
+                query.append_pair("callerId", caller_id);
+            }
 
-        if let Some(timeout) = timeout {
-            url.append_pair("timeout", &timeout.to_string());
+            if let Some(timeout) = timeout {
+                query.append_pair("timeout", &timeout.to_string());
+            }
         }
 
-        reqwest::Client::new().post(url.finish().to_owned()).send().await?;
+        client.post(url).send().await?.ari_error_for_status().await?;
 
         event!(Level::INFO, "dialed channel with id {}", self.id);
         Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub fn get_rtp_statistics(&self, _client: &Client) -> Result<RtpStatistics> {
-        unimplemented!()
+    pub async fn get_rtp_statistics(&self, client: &Client) -> Result<RtpStatistics> {
+        let url = client.url.join(&format!("channels/{}/rtp_statistics", self.id))?;
+
+        let statistics = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<RtpStatistics>()
+            .await?;
+        event!(Level::INFO, "received rtp statistics of channel with id {}", self.id);
+        Ok(statistics)
     }
 
     #[instrument(level = "debug")]
     pub async fn list(client: &Client) -> Result<Vec<Channel>> {
-        let url: Url = client
-            .url
-            .join("channels")?
-            .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
-            .finish()
-            .to_owned();
+        let url: Url = client.url.join("channels")?;
 
-        let channels = reqwest::get(url).await?.json::<Vec<Channel>>().await?;
+        let channels = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Channel>>()
+            .await?;
         event!(Level::INFO, "received channels");
         Ok(channels)
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Create a channel in the Stasis application without dialing it, use [`Channel::dial`] to call it.
     #[instrument(level = "debug")]
-    pub async fn create(
-        client: &Client,
-        endpoint: &str,
-        app: &str,
-        app_args: Vec<&str>,
-        channel_id: Option<&str>,
-        other_channel_id: Option<&str>,
-        originator: Option<&str>,
-        formats: Vec<&str>,
-        variables: HashMap<&str, &str>,
-    ) -> Result<Channel> {
-        let mut url = client.url.join("channels")?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
-        url.append_pair("endpoint", endpoint).append_pair("app", app);
-
-        if !formats.is_empty() {
-            let formats = formats.join(",");
-            url.append_pair("formats", &formats);
+    pub async fn create(client: &Client, request: CreateChannelRequest<'_>) -> Result<Channel> {
+        let mut url = client.url.join("channels/create")?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("endpoint", request.endpoint);
+            OriginateParams::from(request.destination).append_to(&mut query);
+            request.options.append_to(&mut query);
         }
 
-        if !app_args.is_empty() {
-            let app_args = app_args.join(",");
-            url.append_pair("app_args", &app_args);
-        }
+        let channel = Channel::post_request(client, url, &request.options.variables).await?;
+        event!(Level::INFO, "created channel with id {}", channel.id);
+        Ok(channel)
+    }
 
-        if let Some(channel_id) = channel_id {
-            url.append_pair("channel_id", channel_id);
-        }
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, channel_id: &str) -> Result<Channel> {
+        let url = client.url.join(&format!("channels/{}", channel_id))?;
 
-        if let Some(other_channel_id) = other_channel_id {
-            url.append_pair("other_channel_id", other_channel_id);
-        }
+        let channel = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Channel>()
+            .await?;
+        event!(Level::INFO, "received channel with id {}", channel.id);
+        Ok(channel)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn originate<'a, D>(client: &Client, request: OriginateRequest<'a, D>) -> Result<Channel>
+    where
+        D: Into<OriginateParams<'a>> + std::fmt::Debug,
+    {
+        let mut url = client.url.join("channels")?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("endpoint", request.endpoint);
+            request.destination.into().append_to(&mut query);
+
+            if let Some(caller_id) = request.caller_id {
+                query.append_pair("callerId", caller_id);
+            }
+
+            if let Some(timeout) = request.timeout {
+                query.append_pair("timeout", &timeout.to_string());
+            }
 
-        if let Some(originator) = originator {
-            url.append_pair("originator", originator);
+            request.options.append_to(&mut query);
         }
 
+        let channel = Channel::post_request(client, url, &request.options.variables).await?;
+        event!(Level::INFO, "originated channel with id {}", channel.id);
+        Ok(channel)
+    }
+
+    async fn post_request(client: &Client, url: Url, variables: &HashMap<&str, &str>) -> Result<Channel> {
         let body = json!({
             "variables": variables
         });
 
-        let channel = reqwest::Client::new()
-            .post(url.finish().to_owned())
+        let channel = client
+            .post(url)
             .json(&body)
             .send()
             .await?
+            .ari_error_for_status()
+            .await?
             .json::<Channel>()
             .await?;
 
-        event!(Level::INFO, "created channel with id {}", channel.id);
-        Ok(channel)
-    }
-
-    #[instrument(level = "debug")]
-    pub async fn get(client: &Client, channel_id: &str) -> Result<Channel> {
-        let url = client
-            .url
-            .join(&format!("channels/{}", channel_id))?
-            .query_pairs_mut()
-            .append_pair("api_key", &client.get_api_key())
-            .finish()
-            .to_owned();
-
-        let channel = reqwest::get(url).await?.json::<Channel>().await?;
-        event!(Level::INFO, "received channel with id {}", channel.id);
         Ok(channel)
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Start snooping on the channel, the snoop channel is placed in the Stasis application `app`.
+    ///
+    /// `spy` is the direction of audio to listen to, `whisper` the direction to inject audio into.
     #[instrument(level = "debug")]
-    pub async fn originate<'a>(
+    pub async fn snoop(
+        &self,
         client: &Client,
-        endpoint: &str,
-        params: OriginateParams<'a>,
-        caller_id: Option<&str>,
-        timeout: Option<u32>,
-        channel_id: Option<&str>,
-        other_channel_id: Option<&str>,
-        originator: Option<&str>,
-        formats: Vec<&str>,
-        variables: HashMap<&str, &str>,
+        app: &str,
+        app_args: Vec<&str>,
+        spy: Option<Direction>,
+        whisper: Option<Direction>,
+        snoop_id: Option<&str>,
     ) -> Result<Channel> {
-        let mut url = client.url.join("channels")?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
+        let mut url = client.url.join(&format!("channels/{}/snoop", self.id))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("app", app);
 
-        url.append_pair("endpoint", endpoint)
-            .append_pair("timeout", &timeout.unwrap_or(30).to_string());
-
-        if !formats.is_empty() {
-            let formats = formats.join(",");
-            url.append_pair("formats", &formats);
-        }
-
-        match params {
-            OriginateParams::Extension {
-                extension,
-                context,
-                priority,
-                label,
-            } => {
-                url.append_pair("extension", extension);
-
-                if let Some(context) = context {
-                    url.append_pair("context", context);
-                }
+            if !app_args.is_empty() {
+                query.append_pair("appArgs", &app_args.join(","));
+            }
 
-                if let Some(priority) = priority {
-                    url.append_pair("priority", &priority.to_string());
-                }
+            if let Some(spy) = spy {
+                query.append_pair("spy", &format!("{}", spy));
+            }
 
-                if let Some(label) = label {
-                    url.append_pair("label", label);
-                }
+            if let Some(whisper) = whisper {
+                query.append_pair("whisper", &format!("{}", whisper));
             }
-            OriginateParams::Application { app, app_args } => {
-                url.append_pair("app", app);
 
-                if !app_args.is_empty() {
-                    let app_args = app_args.join(",");
-                    url.append_pair("app_args", &app_args);
-                }
+            if let Some(snoop_id) = snoop_id {
+                query.append_pair("snoopId", snoop_id);
             }
         }
 
-        if let Some(caller_id) = caller_id {
-            url.append_pair("callerId", caller_id);
-        }
+        let channel = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Channel>()
+            .await?;
 
-        if let Some(channel_id) = channel_id {
-            url.append_pair("channel_id", channel_id);
-        }
+        event!(Level::INFO, "started snooping on channel with id {} as {}", self.id, channel.id);
+        Ok(channel)
+    }
 
-        if let Some(other_channel_id) = other_channel_id {
-            url.append_pair("other_channel_id", other_channel_id);
-        }
+    #[instrument(level = "debug")]
+    pub async fn snoop_with_id(
+        &self,
+        client: &Client,
+        snoop_id: &str,
+        app: &str,
+        app_args: Vec<&str>,
+        spy: Option<Direction>,
+        whisper: Option<Direction>,
+    ) -> Result<Channel> {
+        let mut url = client.url.join(&format!("channels/{}/snoop/{}", self.id, snoop_id))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("app", app);
 
-        if let Some(originator) = originator {
-            url.append_pair("originator", originator);
-        }
+            if !app_args.is_empty() {
+                query.append_pair("appArgs", &app_args.join(","));
+            }
 
-        let body = json!({
-            "variables": variables
-        });
+            if let Some(spy) = spy {
+                query.append_pair("spy", &format!("{}", spy));
+            }
 
-        let channel = reqwest::Client::new()
-            .post(url.finish().to_owned())
-            .json(&body)
+            if let Some(whisper) = whisper {
+                query.append_pair("whisper", &format!("{}", whisper));
+            }
+        }
+
+        let channel = client
+            .post(url)
             .send()
             .await?
+            .ari_error_for_status()
+            .await?
             .json::<Channel>()
             .await?;
 
-        event!(Level::INFO, "originated channel with id {}", channel.id);
+        event!(Level::INFO, "started snooping on channel with id {} as {}", self.id, channel.id);
         Ok(channel)
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Let `supervisor` listen to, whisper to or barge into the call of this channel.
+    ///
+    /// Creates a snoop channel in the Stasis application `app` and bridges it with the supervisor channel,
+    /// which must already be in that application. Returns the snoop channel and the bridge, destroying the
+    /// bridge and hanging up the snoop channel ends the supervision. `Client::run` must be running, as the
+    /// snoop channel is only bridged once its `StasisStart` has been received, which must happen within
+    /// [`SUPERVISION_TIMEOUT`].
     #[instrument(level = "debug")]
-    pub async fn originate_with_id<'a>(
-        client: &Client,
-        channel_id: &str,
-        endpoint: &str,
-        params: OriginateParams<'a>,
-        caller_id: Option<&str>,
-        timeout: Option<u32>,
-        other_channel_id: Option<&str>,
-        originator: Option<&str>,
-        formats: Vec<&str>,
-        variables: HashMap<&str, &str>,
-    ) -> Result<Channel> {
-        let mut url = client.url.join(&format!("channels/{}", channel_id))?;
-        let mut url = url.query_pairs_mut();
-        client.add_api_key(&mut url);
-
-        url.append_pair("endpoint", endpoint)
-            .append_pair("timeout", &timeout.unwrap_or(30).to_string());
+    pub async fn supervise(&self, client: &Client, supervisor: &Channel, mode: SupervisionMode, app: &str) -> Result<(Channel, Bridge)> {
+        let whisper = match mode {
+            SupervisionMode::Listen => None,
+            SupervisionMode::Whisper => Some(Direction::Out),
+            SupervisionMode::Barge => Some(Direction::Both),
+        };
 
-        if !formats.is_empty() {
-            let formats = formats.join(",");
-            url.append_pair("formats", &formats);
+        if client.app_name != app && !client.additional_app_names.iter().any(|name| name == app) {
+            return Err(AriError::UnregisteredApplication(app.to_string()));
         }
 
-        match params {
-            OriginateParams::Extension {
-                extension,
-                context,
-                priority,
-                label,
-            } => {
-                url.append_pair("extension", extension);
+        let snoop_id = format!("snoop-{:016x}", rand::random::<u64>());
+        let mut events = client.channel_events(&snoop_id);
 
-                if let Some(context) = context {
-                    url.append_pair("context", context);
-                }
+        let snoop = self
+            .snoop_with_id(client, &snoop_id, app, vec![], Some(Direction::Both), whisper)
+            .await?;
 
-                if let Some(priority) = priority {
-                    url.append_pair("priority", &priority.to_string());
+        let started = timeout(SUPERVISION_TIMEOUT, async {
+            loop {
+                match events.next().await {
+                    Some(Event::StasisStart(_)) => break true,
+                    Some(_) => continue,
+                    None => break false,
                 }
+            }
+        });
 
-                if let Some(label) = label {
-                    url.append_pair("label", label);
-                }
+        match started.await {
+            Ok(true) => {}
+            Ok(false) => return Err(AriError::ChannelEnded(snoop.id)),
+            Err(_) => {
+                snoop.discard_supervision(client, None).await;
+                return Err(AriError::StasisStartTimeout(snoop.id));
             }
-            OriginateParams::Application { app, app_args } => {
-                url.append_pair("app", app);
+        }
 
-                if !app_args.is_empty() {
-                    let app_args = app_args.join(",");
-                    url.append_pair("app_args", &app_args);
-                }
+        let bridge = match Bridge::create_bridge(client, vec![BridgeType::Mixing], None).await {
+            Ok(bridge) => bridge,
+            Err(e) => {
+                snoop.discard_supervision(client, None).await;
+                return Err(e);
             }
+        };
+
+        let bridged = async {
+            bridge.add_channel(client, &snoop.id, None, false, false).await?;
+            bridge.add_channel(client, &supervisor.id, None, false, false).await
+        };
+
+        if let Err(e) = bridged.await {
+            snoop.discard_supervision(client, Some(&bridge)).await;
+            return Err(e);
         }
 
-        if let Some(caller_id) = caller_id {
-            url.append_pair("callerId", caller_id);
+        event!(
+            Level::INFO,
+            "channel with id {} supervises channel with id {} in bridge with id {}",
+            supervisor.id,
+            self.id,
+            bridge.id
+        );
+        Ok((snoop, bridge))
+    }
+
+    /// Best effort cleanup of a failed [`Channel::supervise`], errors are only logged.
+    async fn discard_supervision(&self, client: &Client, bridge: Option<&Bridge>) {
+        if let Some(bridge) = bridge {
+            if let Err(e) = bridge.destroy(client).await {
+                event!(Level::WARN, "failed to destroy supervision bridge with id {}: {}", bridge.id, e);
+            }
         }
 
-        if let Some(other_channel_id) = other_channel_id {
-            url.append_pair("otherChannelId", other_channel_id);
+        if let Err(e) = self.clone().hangup(client, Reason::Normal).await {
+            event!(Level::WARN, "failed to hang up snoop channel with id {}: {}", self.id, e);
         }
+    }
 
-        if let Some(originator) = originator {
-            url.append_pair("originator", originator);
+    #[instrument(level = "debug")]
+    pub async fn start_external_media(client: &Client, request: ExternalMediaRequest<'_>) -> Result<Channel> {
+        let mut url = client.url.join("channels/externalMedia")?;
+        {
+            let mut query = url.query_pairs_mut();
+            request.append_to(&mut query);
         }
 
-        let body = json!({
-            "variables": variables
-        });
+        let channel = Channel::post_request(client, url, &request.variables).await?;
+        event!(
+            Level::INFO,
+            "started external media channel with id {} to {}",
+            channel.id,
+            request.external_host
+        );
+        Ok(channel)
+    }
+}
 
-        let channel = reqwest::Client::new()
-            .post(url.finish().to_owned())
-            .json(&body)
-            .send()
-            .await?
-            .json::<Channel>()
-            .await?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        event!(Level::INFO, "originated channel with id {}", channel.id);
-        Ok(channel)
+    fn query(append: impl FnOnce(&mut form_urlencoded::Serializer<UrlQuery>)) -> String {
+        let mut url = Url::parse("http://localhost:8088/ari/channels").unwrap();
+        append(&mut url.query_pairs_mut());
+        url.query().unwrap_or_default().to_string()
     }
 
-    #[instrument(level = "debug")]
-    pub fn snoop(&self, _channel_id: &str) -> Result<Channel> {
-        unimplemented!()
+    #[test]
+    fn extension_query() {
+        let request = OriginateRequest::extension("PJSIP/1000", "600")
+            .context("from-internal")
+            .priority(2)
+            .label("start");
+
+        let params = OriginateParams::from(request.destination);
+
+        assert_eq!(
+            query(|query| params.append_to(query)),
+            "extension=600&context=from-internal&priority=2&label=start"
+        );
     }
 
-    #[instrument(level = "debug")]
-    pub fn snoop_with_id(&self, _channel_id: &str) -> Result<Channel> {
-        unimplemented!()
+    #[test]
+    fn application_query() {
+        let request = OriginateRequest::application("PJSIP/1000", "ari").app_args(vec!["inbound", "queue=support"]);
+
+        let params = OriginateParams::from(request.destination);
+
+        assert_eq!(query(|query| params.append_to(query)), "app=ari&appArgs=inbound%2Cqueue%3Dsupport");
     }
 
-    #[instrument(level = "debug")]
-    pub fn start_external_media(&self, _channel_id: &str) -> Result<Channel> {
-        unimplemented!()
+    #[test]
+    fn minimal_destination_query() {
+        let extension = OriginateParams::from(OriginateRequest::extension("PJSIP/1000", "600").destination);
+        let application = OriginateParams::from(CreateChannelRequest::new("PJSIP/1000", "ari").destination);
+
+        assert_eq!(query(|query| extension.append_to(query)), "extension=600");
+        assert_eq!(query(|query| application.append_to(query)), "app=ari");
+    }
+
+    #[test]
+    fn channel_options_query() {
+        let request = CreateChannelRequest::new("Local/600@default", "ari")
+            .channel_id("first")
+            .other_channel_id("second")
+            .originator("caller")
+            .formats(vec!["ulaw", "alaw"])
+            .variable("CALLERID(name)", "Alice");
+
+        assert_eq!(
+            query(|query| request.options.append_to(query)),
+            "channelId=first&otherChannelId=second&originator=caller&formats=ulaw%2Calaw"
+        );
+        assert_eq!(request.options.variables["CALLERID(name)"], "Alice");
+        assert_eq!(query(|query| ChannelOptions::default().append_to(query)), "");
+    }
+
+    #[test]
+    fn external_media_query() {
+        let request = ExternalMediaRequest::new("ari", "127.0.0.1:4000", "slin16")
+            .encapsulation(Encapsulation::Rtp)
+            .transport(Transport::Udp)
+            .direction(Direction::Both)
+            .channel_id("media")
+            .variable("CALLERID(name)", "Alice");
+
+        assert_eq!(
+            query(|query| request.append_to(query)),
+            "app=ari&external_host=127.0.0.1%3A4000&format=slin16&encapsulation=rtp&transport=udp&direction=both&channelId=media"
+        );
+        assert_eq!(request.variables["CALLERID(name)"], "Alice");
+    }
+
+    #[test]
+    fn deserialize_channel_without_optional_fields() {
+        // Asterisk 16.30
+        let payload = r#"{
+            "id": "1697536800.12",
+            "name": "SIP/alice-0000000c",
+            "state": "Ring",
+            "caller": {"name": "Alice", "number": "1001"},
+            "connected": {"name": "", "number": ""},
+            "accountcode": "",
+            "dialplan": {"context": "from-internal", "exten": "600", "priority": 2},
+            "creationtime": "2023-10-17T10:00:00.452+0200",
+            "language": "en"
+        }"#;
+
+        let channel: Channel = serde_json::from_str(payload).unwrap();
+
+        assert_eq!(channel.state, ChannelState::Ring);
+        assert_eq!(channel.protocol_id, None);
+        assert_eq!(channel.creationtime.to_rfc3339(), "2023-10-17T08:00:00.452+00:00");
+        assert_eq!(channel.dialplan.app_name, "");
+        assert!(channel.channelvars.is_none());
+    }
+
+    #[test]
+    fn deserialize_stasis_start() {
+        // Asterisk 20.5
+        let payload = r#"{
+            "type": "StasisStart",
+            "timestamp": "2024-10-17T10:12:31.874+0000",
+            "args": [],
+            "channel": {
+                "id": "1729159951.94",
+                "name": "PJSIP/1001-0000002e",
+                "state": "Up",
+                "protocol_id": "d5e1a3c0-52ef-4d7c-8f3b-1c0b5e5a7b64",
+                "caller": {"name": "Alice", "number": "1001"},
+                "connected": {"name": "", "number": ""},
+                "accountcode": "",
+                "dialplan": {"context": "from-internal", "exten": "600", "priority": 3, "app_name": "Stasis", "app_data": "ari"},
+                "creationtime": "2024-10-17T10:12:31.702+0000",
+                "language": "en",
+                "channelvars": {"CDR(userfield)": "", "QUEUE_NAME": "support"}
+            },
+            "asterisk_id": "52:54:00:12:34:56",
+            "application": "ari"
+        }"#;
+
+        let Event::StasisStart(event) = serde_json::from_str(payload).unwrap() else {
+            panic!("expected a StasisStart event");
+        };
+
+        assert_eq!(event.channel.state, ChannelState::Up);
+        assert_eq!(event.channel.protocol_id.as_deref(), Some("d5e1a3c0-52ef-4d7c-8f3b-1c0b5e5a7b64"));
+        assert_eq!(event.channel.dialplan.app_name, "Stasis");
+        assert_eq!(event.channel.channelvars.unwrap()["QUEUE_NAME"], "support");
+    }
+
+    #[test]
+    fn deserialize_channel_state_change() {
+        // Asterisk 22.1
+        let payload = r#"{
+            "type": "ChannelStateChange",
+            "timestamp": "2024-12-02T15:41:09.118+0100",
+            "channel": {
+                "id": "asterisk-1733150469.7",
+                "name": "PJSIP/trunk-00000003",
+                "state": "Dialing Offhook",
+                "protocol_id": "",
+                "caller": {"name": "", "number": "0612345678"},
+                "connected": {"name": "", "number": ""},
+                "accountcode": "",
+                "dialplan": {"context": "default", "exten": "s", "priority": 1, "app_name": "AppDial2", "app_data": "(Outgoing Line)"},
+                "creationtime": "2024-12-02T15:41:08.996+0100",
+                "language": "fr",
+                "caller_rdnis": "0100000000",
+                "tenantid": "acme"
+            },
+            "asterisk_id": "52:54:00:ab:cd:ef",
+            "application": "ari"
+        }"#;
+
+        let Event::ChannelStateChange(event) = serde_json::from_str(payload).unwrap() else {
+            panic!("expected a ChannelStateChange event");
+        };
+
+        assert_eq!(event.channel.state, ChannelState::DialingOffhook);
+        assert_eq!(event.channel.caller_rdnis.as_deref(), Some("0100000000"));
+        assert_eq!(event.channel.tenantid.as_deref(), Some("acme"));
+    }
+
+    #[test]
+    fn deserialize_channel_states() {
+        let states = [
+            ("\"Down\"", ChannelState::Down),
+            ("\"Rsrvd\"", ChannelState::Rsrvd),
+            ("\"OffHook\"", ChannelState::OffHook),
+            ("\"Dialing\"", ChannelState::Dialing),
+            ("\"Ringing\"", ChannelState::Ringing),
+            ("\"Busy\"", ChannelState::Busy),
+            ("\"Pre-ring\"", ChannelState::PreRing),
+            ("\"Unknown\"", ChannelState::Unknown),
+            ("\"Mute\"", ChannelState::Unknown),
+        ];
+
+        for (payload, state) in states {
+            assert_eq!(serde_json::from_str::<ChannelState>(payload).unwrap(), state);
+        }
     }
 }
@@ -1,13 +1,33 @@
-use std::time::Duration;
+use std::{fmt, sync::Mutex, time::Duration};
 
+use base64::{engine::general_purpose::STANDARD, Engine};
 use futures_util::{SinkExt, StreamExt};
+use native_tls::TlsConnector;
 use rand::Rng;
-use tokio::{sync::mpsc::Sender, time::interval};
-use tokio_tungstenite::{connect_async, tungstenite};
+use tokio::{
+    net::TcpStream,
+    sync::mpsc::Sender,
+    time::{interval, sleep},
+};
+use tokio_tungstenite::{
+    connect_async_tls_with_config,
+    tungstenite::{
+        self,
+        client::IntoClientRequest,
+        handshake::client::Request,
+        http::{header::AUTHORIZATION, HeaderValue},
+    },
+    Connector, MaybeTlsStream, WebSocketStream,
+};
 use tracing::{event, instrument, Level};
 use url::Url;
 
-use crate::{Event, Result};
+use crate::{
+    dispatcher::{Backpressure, Dispatcher, EventReceiver, EventStream, Resource, StasisStartStream},
+    AriError, Event, Result,
+};
+
+pub use native_tls::Certificate;
 
 impl ClientBuilder {
     pub fn url(mut self, url: Url) -> Self {
@@ -30,8 +50,87 @@ impl ClientBuilder {
         self
     }
 
-    pub fn handler(mut self, tx: Sender<Event>) -> Self {
-        self.0.ws_channel = Some(tx);
+    /// Register another application on the same WebSocket, may be called several times.
+    ///
+    /// REST calls that take an application, such as [`Channel::snoop`](crate::channel::Channel::snoop), still use [`ClientBuilder::app_name`].
+    pub fn additional_app_name(mut self, app_name: &str) -> Self {
+        self.0.additional_app_names.push(app_name.to_string());
+        self
+    }
+
+    /// Whether the applications receive the events of every resource, defaults to `true`.
+    ///
+    /// When disabled, only resources the applications are involved in or explicitly subscribed to through
+    /// [`Application::subscribe`](crate::application::Application::subscribe) produce events.
+    pub fn subscribe_all(mut self, subscribe_all: bool) -> Self {
+        self.0.subscribe_all = subscribe_all;
+        self
+    }
+
+    /// Forward every event to `tx`, may be called several times to register several handlers.
+    pub fn handler(self, tx: Sender<Event>) -> Self {
+        self.0.handlers.lock().unwrap().push(tx);
+        self
+    }
+
+    /// Policy applied when a subscriber does not keep up, defaults to [`Backpressure::DropNewest`].
+    pub fn backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.0.dispatcher.backpressure = backpressure;
+        self
+    }
+
+    /// Number of events buffered per subscriber before the backpressure policy kicks in.
+    pub fn event_capacity(mut self, capacity: usize) -> Self {
+        self.0.dispatcher.capacity = capacity;
+        self
+    }
+
+    /// Reconnect to Asterisk according to `policy` whenever the WebSocket closes or fails.
+    pub fn reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.0.reconnect = Some(policy);
+        self
+    }
+
+    /// Authenticate the WebSocket handshake with an `api_key` query parameter instead of HTTP Basic authentication.
+    ///
+    /// Only needed for setups that cannot forward the `Authorization` header, the credentials end up in the URL.
+    pub fn websocket_api_key(mut self, enabled: bool) -> Self {
+        self.0.ws_api_key = enabled;
+        self
+    }
+
+    /// Timeout for a whole REST request, from connecting until the response body has been read.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.1 = self.1.timeout(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.1 = self.1.connect_timeout(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.1 = self.1.proxy(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. a private CA used by the PBX, for both REST calls and the WebSocket.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.2.root_certificates.push(certificate);
+        self
+    }
+
+    /// Accept any TLS certificate presented by Asterisk, for both REST calls and the WebSocket.
+    ///
+    /// Only meant for lab setups with self-signed certificates, this disables all certificate validation.
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.2.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.1 = self.1.user_agent(user_agent);
         self
     }
 
@@ -52,38 +151,118 @@ impl ClientBuilder {
             return Err(tungstenite::error::UrlError::UnsupportedUrlScheme.into());
         }
 
+        let apps = [&self.0.app_name]
+            .into_iter()
+            .chain(self.0.additional_app_names.iter())
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+
         ws_url
             .query_pairs_mut()
-            .append_pair("app", &self.0.app_name)
-            .append_pair("api_key", &format!("{}:{}", self.0.username, self.0.password))
-            .append_pair("subscribeAll", "true");
+            .append_pair("app", &apps)
+            .append_pair("subscribeAll", &self.0.subscribe_all.to_string());
 
         event!(Level::TRACE, "Using REST API server with URL '{}'", self.0.url);
 
         event!(Level::TRACE, "Using WebSocket server with URL '{}'", ws_url);
 
+        let tls = self.2.connector()?;
+
+        let http = match &tls {
+            Some(tls) => self.1.use_preconfigured_tls(tls.clone()),
+            None => self.1,
+        };
+
         Ok(Client {
+            http: http.build()?,
+            tls,
             url: self.0.url,
             ws_url,
             username: self.0.username,
             password: self.0.password,
             app_name: self.0.app_name,
-            ws_channel: self.0.ws_channel,
+            additional_app_names: self.0.additional_app_names,
+            subscribe_all: self.0.subscribe_all,
+            handlers: self.0.handlers,
+            ws_api_key: self.0.ws_api_key,
+            reconnect: self.0.reconnect,
+            dispatcher: self.0.dispatcher,
         })
     }
 }
 
 #[derive(Debug, Default)]
-pub struct ClientBuilder(Client);
+pub struct ClientBuilder(Client, reqwest::ClientBuilder, TlsSettings);
+
+/// TLS settings shared by the REST client and the WebSocket connection.
+#[derive(Default)]
+struct TlsSettings {
+    root_certificates: Vec<Certificate>,
+    accept_invalid_certs: bool,
+}
+
+impl TlsSettings {
+    /// `None` when the defaults apply, so the clients keep their own TLS configuration.
+    fn connector(&self) -> Result<Option<TlsConnector>> {
+        if self.root_certificates.is_empty() && !self.accept_invalid_certs {
+            return Ok(None);
+        }
+
+        let mut builder = TlsConnector::builder();
+
+        for certificate in &self.root_certificates {
+            builder.add_root_certificate(certificate.clone());
+        }
+
+        Ok(Some(builder.danger_accept_invalid_certs(self.accept_invalid_certs).build()?))
+    }
+}
+
+impl fmt::Debug for TlsSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TlsSettings")
+            .field("root_certificates", &self.root_certificates.len())
+            .field("accept_invalid_certs", &self.accept_invalid_certs)
+            .finish()
+    }
+}
 
 impl Client {
     #[allow(clippy::new_ret_no_self)]
     pub fn new() -> ClientBuilder {
-        ClientBuilder(Client::default())
+        ClientBuilder(Client::default(), reqwest::Client::builder(), TlsSettings::default())
+    }
+
+    pub(crate) fn get(&self, url: Url) -> reqwest::RequestBuilder {
+        self.http.get(url).basic_auth(&self.username, Some(&self.password))
+    }
+
+    pub(crate) fn post(&self, url: Url) -> reqwest::RequestBuilder {
+        self.http.post(url).basic_auth(&self.username, Some(&self.password))
+    }
+
+    pub(crate) fn put(&self, url: Url) -> reqwest::RequestBuilder {
+        self.http.put(url).basic_auth(&self.username, Some(&self.password))
+    }
+
+    pub(crate) fn delete(&self, url: Url) -> reqwest::RequestBuilder {
+        self.http.delete(url).basic_auth(&self.username, Some(&self.password))
+    }
+
+    /// Append `segments` to `path`, escaping each one so ids such as `digits/1` stay a single path segment.
+    pub(crate) fn segment_url(&self, path: &str, segments: &[&str]) -> Result<Url> {
+        let mut url = self.url.join(&format!("{}/", path))?;
+
+        if let Ok(mut path_segments) = url.path_segments_mut() {
+            path_segments.pop_if_empty().extend(segments);
+        }
+
+        Ok(url)
     }
 
     #[instrument(level = "debug")]
-    pub fn handle_message(&self, message: Vec<u8>) {
+    pub async fn handle_message(&self, message: Vec<u8>) {
         let data = String::from_utf8(message).unwrap();
 
         event!(Level::TRACE, "Parsing event");
@@ -97,30 +276,132 @@ impl Client {
             }
         };
 
-        event!(Level::TRACE, "Event parsed successfully");
+        if let Event::Unknown(value) = &event {
+            event!(Level::DEBUG, "Received untyped event '{}'", value["type"]);
+        } else {
+            event!(Level::TRACE, "Event parsed successfully");
+        }
 
-        if let Some(tx) = &self.ws_channel {
-            event!(Level::INFO, "Sending event to channel");
-            if let Err(e) = tx.try_send(event) {
-                event!(Level::ERROR, "Error sending event: {}", e);
-            }
+        self.dispatcher.dispatch(&event);
+        self.send_event(event).await;
+    }
+
+    /// Subscribe to every event received by the client.
+    ///
+    /// Each subscriber gets its own queue, a subscriber that falls behind is handled according to the
+    /// configured [`Backpressure`] policy without affecting the others.
+    pub fn subscribe(&self) -> EventReceiver {
+        self.dispatcher.receiver()
+    }
+
+    /// Number of events discarded so far because a subscriber's queue was full.
+    pub fn dropped_events(&self) -> u64 {
+        self.dispatcher.dropped()
+    }
+
+    /// Stream of the channels entering the application, each with the stream of its events.
+    ///
+    /// Unlike calling [`Client::channel_events`] after receiving a `StasisStart`, no event of the channel can be missed.
+    pub fn stasis_starts(&self) -> StasisStartStream {
+        self.dispatcher.stasis_starts()
+    }
+
+    /// Stream of the events referencing the channel, ending on `StasisEnd` or `ChannelDestroyed`.
+    ///
+    /// Events received before subscribing are not replayed, use [`Client::stasis_starts`] to follow channels entering the application.
+    pub fn channel_events(&self, channel_id: &str) -> EventStream {
+        self.dispatcher.subscribe(Resource::Channel(channel_id.to_string()))
+    }
+
+    /// Stream of the events referencing the bridge, ending on `BridgeDestroyed`.
+    pub fn bridge_events(&self, bridge_id: &str) -> EventStream {
+        self.dispatcher.subscribe(Resource::Bridge(bridge_id.to_string()))
+    }
+
+    /// Stream of the events of the playback, ending on `PlaybackFinished`.
+    pub fn playback_events(&self, playback_id: &str) -> EventStream {
+        self.dispatcher.subscribe(Resource::Playback(playback_id.to_string()))
+    }
+
+    /// Stream of the events of the live recording, ending on `RecordingFinished` or `RecordingFailed`.
+    pub fn recording_events(&self, recording_name: &str) -> EventStream {
+        self.dispatcher.subscribe(Resource::Recording(recording_name.to_string()))
+    }
+
+    /// Stream of the events referencing the endpoint, such as `EndpointStateChange`. Endpoints outlive calls so it never ends.
+    pub fn endpoint_events(&self, technology: &str, resource: &str) -> EventStream {
+        self.dispatcher
+            .subscribe(Resource::Endpoint(technology.to_string(), resource.to_string()))
+    }
+
+    async fn send_event(&self, event: Event) {
+        event!(Level::INFO, "Sending event to subscribers");
+        self.dispatcher.broadcast(event).await;
+    }
+
+    fn spawn_handlers(&self) {
+        for tx in self.handlers.lock().unwrap().drain(..) {
+            let mut rx = self.subscribe();
+            tokio::spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    if let Err(e) = tx.send(event).await {
+                        event!(Level::ERROR, "Error sending event: {}", e);
+                        break;
+                    }
+                }
+            });
         }
     }
 
+    /// Connect to Asterisk and dispatch events until the WebSocket closes.
+    ///
+    /// With a [`ReconnectPolicy`] configured, the connection is re-established after every
+    /// disconnect and the method only returns once the policy gives up.
     #[instrument(level = "debug")]
     pub async fn run(&self) -> Result<()> {
-        event!(Level::INFO, "Connecting to Asterisk");
+        self.spawn_handlers();
+        let mut attempt = 0;
 
-        let (ws_stream, _) = match connect_async(&self.ws_url.to_string()).await {
-            Ok(stream) => stream,
-            Err(e) => {
-                event!(Level::ERROR, "Failed to connect to Asterisk: {}", e);
-                return Err(e.into());
+        loop {
+            event!(Level::INFO, "Connecting to Asterisk");
+
+            let connector = self.tls.clone().map(Connector::NativeTls);
+
+            let result = match connect_async_tls_with_config(self.ws_request()?, None, false, connector).await {
+                Ok((ws_stream, _)) => {
+                    event!(Level::INFO, "WebSocket handshake has been successfully completed");
+                    attempt = 0;
+                    self.send_event(Event::Connection(ConnectionEvent::Connected)).await;
+                    let result = self.listen(ws_stream).await;
+                    self.send_event(Event::Connection(ConnectionEvent::Disconnected)).await;
+                    result
+                }
+                Err(e) => {
+                    event!(Level::ERROR, "Failed to connect to Asterisk: {}", e);
+                    Err(e.into())
+                }
+            };
+
+            let Some(policy) = &self.reconnect else {
+                return result;
+            };
+
+            if policy.max_attempts.is_some_and(|max_attempts| attempt >= max_attempts) {
+                event!(Level::ERROR, "Giving up reconnecting to Asterisk after {} attempts", attempt);
+                return result;
             }
-        };
 
-        event!(Level::INFO, "WebSocket handshake has been successfully completed");
+            attempt += 1;
+            let delay = policy.delay(attempt);
+
+            event!(Level::INFO, "Reconnecting to Asterisk in {:?} (attempt {})", delay, attempt);
+            self.send_event(Event::Connection(ConnectionEvent::Reconnecting { attempt, delay }))
+                .await;
+            sleep(delay).await;
+        }
+    }
 
+    async fn listen(&self, ws_stream: WebSocketStream<MaybeTlsStream<TcpStream>>) -> Result<()> {
         let (mut ws_sender, mut ws_receiver) = ws_stream.split();
         let mut interval = interval(Duration::from_millis(5000));
 
@@ -133,7 +414,7 @@ impl Client {
                             match message {
                                 tungstenite::Message::Text(_) => {
                                     event!(Level::INFO, "Received WebSocket Text");
-                                    self.handle_message(message.into_data());
+                                    self.handle_message(message.into_data()).await;
                                 }
                                 tungstenite::Message::Ping(data) => {
                                     event!(Level::INFO, "Received WebSocket Ping, sending Pong");
@@ -170,40 +451,248 @@ impl Client {
         Ok(())
     }
 
-    pub(crate) fn get_api_key(&self) -> String {
-        format!("{}:{}", self.username, self.password)
-    }
+    fn ws_request(&self) -> Result<Request> {
+        let credentials = format!("{}:{}", self.username, self.password);
 
-    pub(crate) fn add_api_key(&self, url: &mut url::form_urlencoded::Serializer<url::UrlQuery>) {
-        url.append_pair("api_key", &self.get_api_key());
+        if self.ws_api_key {
+            let mut url = self.ws_url.clone();
+            url.query_pairs_mut().append_pair("api_key", &credentials);
+            return Ok(url.as_str().into_client_request()?);
+        }
+
+        let mut request = self.ws_url.as_str().into_client_request()?;
+        let authorization = HeaderValue::from_str(&format!("Basic {}", STANDARD.encode(credentials)))
+            .map_err(|_| AriError::Unknown("Credentials contain invalid header characters".to_string()))?;
+        request.headers_mut().insert(AUTHORIZATION, authorization);
+
+        Ok(request)
     }
 }
 
 impl Default for Client {
     fn default() -> Self {
         Self {
+            http: reqwest::Client::new(),
+            tls: None,
             url: match Url::parse("http://localhost:8088/") {
                 Ok(url) => url,
                 Err(_) => panic!("Failed to parse URL"),
             },
-            ws_url: match Url::parse("ws://localhost:8088/ari/events?app=ari&api_key=asterisk:asterisk&subscribeAll=true") {
+            ws_url: match Url::parse("ws://localhost:8088/ari/events?app=ari&subscribeAll=true") {
                 Ok(url) => url,
                 Err(_) => panic!("Failed to parse URL"),
             },
             username: "asterisk".to_string(),
             password: "asterisk".to_string(),
             app_name: "ari".to_string(),
-            ws_channel: None,
+            additional_app_names: Vec::new(),
+            subscribe_all: true,
+            handlers: Mutex::new(Vec::new()),
+            ws_api_key: false,
+            reconnect: None,
+            dispatcher: Dispatcher::default(),
         }
     }
 }
 
-#[derive(Debug)]
 pub struct Client {
+    http: reqwest::Client,
+    tls: Option<TlsConnector>,
     pub url: Url,
     pub ws_url: Url,
     pub username: String,
     pub password: String,
     pub app_name: String,
-    pub ws_channel: Option<Sender<Event>>,
+    pub additional_app_names: Vec<String>,
+    pub subscribe_all: bool,
+    handlers: Mutex<Vec<Sender<Event>>>,
+    pub ws_api_key: bool,
+    pub reconnect: Option<ReconnectPolicy>,
+    dispatcher: Dispatcher,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("url", &self.url.as_str())
+            .field("ws_url", &self.ws_url.as_str())
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("app_name", &self.app_name)
+            .field("additional_app_names", &self.additional_app_names)
+            .field("subscribe_all", &self.subscribe_all)
+            .field("backpressure", &self.dispatcher.backpressure)
+            .field("event_capacity", &self.dispatcher.capacity)
+            .field("ws_api_key", &self.ws_api_key)
+            .field("reconnect", &self.reconnect)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Exponential backoff with jitter used by [`Client::run`] to reconnect to Asterisk.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    /// Fraction of the delay, between 0 and 1, that is randomly added or removed.
+    pub jitter: f64,
+    /// Give up after this many consecutive failed attempts, `None` retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let max_delay = self.max_delay.as_secs_f64();
+        let delay = (self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent)).min(max_delay);
+        let jitter = delay * self.jitter * rand::thread_rng().gen_range(-1.0..=1.0);
+
+        // `f64::max`/`min` discard NaN, and converting back may still overflow when `max_delay` is close to `Duration::MAX`
+        let delay = (delay + jitter).max(0.0).min(max_delay);
+        Duration::try_from_secs_f64(delay).map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_attempts: None,
+        }
+    }
+}
+
+/// Synthetic events describing the state of the WebSocket connection to Asterisk.
+#[derive(Debug, Clone)]
+pub enum ConnectionEvent {
+    Connected,
+    Disconnected,
+    Reconnecting { attempt: u32, delay: Duration },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(websocket_api_key: bool) -> Client {
+        Client::new()
+            .url(Url::parse("http://localhost:8088/ari/").unwrap())
+            .username("asterisk")
+            .password("s3cr3t")
+            .websocket_api_key(websocket_api_key)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn debug_redacts_password() {
+        let debug = format!("{:?}", client(false));
+
+        assert!(debug.contains("asterisk"), "{}", debug);
+        assert!(!debug.contains("s3cr3t"), "{}", debug);
+    }
+
+    #[test]
+    fn websocket_uses_basic_auth_by_default() {
+        let request = client(false).ws_request().unwrap();
+
+        let authorization = request.headers()[AUTHORIZATION].to_str().unwrap();
+        assert_eq!(authorization, format!("Basic {}", STANDARD.encode("asterisk:s3cr3t")));
+        assert!(!request.uri().to_string().contains("api_key"), "{}", request.uri());
+    }
+
+    #[test]
+    fn websocket_api_key_only_when_enabled() {
+        let request = client(true).ws_request().unwrap();
+
+        assert!(request.uri().to_string().contains("api_key=asterisk%3As3cr3t"), "{}", request.uri());
+        assert!(!request.headers().contains_key(AUTHORIZATION));
+    }
+
+    #[test]
+    fn segment_url_escapes_each_segment() {
+        let client = Client::default();
+
+        let url = client.segment_url("deviceStates", &["PJSIP/1000"]).unwrap();
+        assert!(url.as_str().ends_with("/deviceStates/PJSIP%2F1000"), "{}", url);
+
+        let url = client.segment_url("recordings/stored", &["greeting", "file"]).unwrap();
+        assert!(url.as_str().ends_with("/recordings/stored/greeting/file"), "{}", url);
+    }
+
+    #[test]
+    fn backoff_doubles_until_max_delay() {
+        let policy = ReconnectPolicy::default().jitter(0.0);
+
+        let delays = (1..=9).map(|attempt| policy.delay(attempt).as_millis()).collect::<Vec<_>>();
+
+        assert_eq!(delays, [500, 1000, 2000, 4000, 8000, 16000, 30000, 30000, 30000]);
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = ReconnectPolicy::default().jitter(0.5);
+
+        for _ in 0..1000 {
+            let delay = policy.delay(2);
+            assert!(
+                delay >= Duration::from_millis(500) && delay <= Duration::from_millis(1500),
+                "{:?}",
+                delay
+            );
+        }
+    }
+
+    #[test]
+    fn jitter_never_exceeds_max_delay() {
+        let policy = ReconnectPolicy::default().jitter(1.0);
+
+        for attempt in 1..1000 {
+            assert!(policy.delay(attempt) <= policy.max_delay);
+        }
+    }
+
+    #[test]
+    fn unbounded_max_delay_saturates() {
+        let policy = ReconnectPolicy::unlimited().max_delay(Duration::MAX).jitter(1.0);
+
+        for attempt in [1, 80, 1000, u32::MAX] {
+            assert!(policy.delay(attempt) <= Duration::MAX);
+        }
+
+        assert_eq!(policy.jitter(0.0).delay(u32::MAX), Duration::MAX);
+    }
 }
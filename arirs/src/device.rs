@@ -1,7 +1,11 @@
 use chrono::DateTime;
+use derive_more::Display;
 use serde::{Deserialize, Serialize};
+use tracing::{event, instrument, Level};
 
-#[derive(Serialize, Deserialize, Debug)]
+use crate::{client::Client, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct DeviceStateChanged {
     pub application: String,
@@ -10,9 +14,99 @@ pub struct DeviceStateChanged {
     pub asterisk_id: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct DeviceState {
     pub name: String,
-    pub state: String,
+    pub state: DeviceStateValue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceStateValue {
+    #[serde(rename = "UNKNOWN")]
+    #[display("UNKNOWN")]
+    Unknown,
+    #[serde(rename = "NOT_INUSE")]
+    #[display("NOT_INUSE")]
+    NotInUse,
+    #[serde(rename = "INUSE")]
+    #[display("INUSE")]
+    InUse,
+    #[serde(rename = "BUSY")]
+    #[display("BUSY")]
+    Busy,
+    #[serde(rename = "INVALID")]
+    #[display("INVALID")]
+    Invalid,
+    #[serde(rename = "UNAVAILABLE")]
+    #[display("UNAVAILABLE")]
+    Unavailable,
+    #[serde(rename = "RINGING")]
+    #[display("RINGING")]
+    Ringing,
+    #[serde(rename = "RINGINUSE")]
+    #[display("RINGINUSE")]
+    RingInUse,
+    #[serde(rename = "ONHOLD")]
+    #[display("ONHOLD")]
+    OnHold,
+}
+
+impl DeviceState {
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client) -> Result<Vec<DeviceState>> {
+        let url = client.url.join("deviceStates")?;
+
+        let device_states = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<DeviceState>>()
+            .await?;
+        event!(Level::INFO, "received device states");
+        Ok(device_states)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, device_name: &str) -> Result<DeviceState> {
+        let url = client.segment_url("deviceStates", &[device_name])?;
+
+        let device_state = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<DeviceState>()
+            .await?;
+        event!(Level::INFO, "received device state of {}", device_name);
+        Ok(device_state)
+    }
+
+    /// Change the state of a device controlled by ARI, its name must start with `Stasis:`.
+    #[instrument(level = "debug")]
+    pub async fn update(client: &Client, device_name: &str, state: DeviceStateValue) -> Result<()> {
+        let url = client
+            .segment_url("deviceStates", &[device_name])?
+            .query_pairs_mut()
+            .append_pair("deviceState", &state.to_string())
+            .finish()
+            .to_owned();
+
+        client.put(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "set device state of {} to {}", device_name, state);
+        Ok(())
+    }
+
+    /// Destroy a device controlled by ARI, its name must start with `Stasis:`.
+    #[instrument(level = "debug")]
+    pub async fn delete(client: &Client, device_name: &str) -> Result<()> {
+        let url = client.segment_url("deviceStates", &[device_name])?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "deleted device state of {}", device_name);
+        Ok(())
+    }
 }
@@ -0,0 +1,550 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+};
+
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::{Stream, StreamExt};
+use tokio::sync::Notify;
+use tracing::{event, Level};
+
+use crate::{
+    bridge::Bridge,
+    channel::{Channel, StasisStart},
+    endpoint::Endpoint,
+    Event,
+};
+
+/// A resource whose events can be followed with a dedicated [`EventStream`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resource {
+    Channel(String),
+    Bridge(String),
+    Playback(String),
+    Recording(String),
+    /// Technology and resource of the endpoint.
+    Endpoint(String, String),
+}
+
+/// Events referencing a single [`Resource`], ending once the resource is gone.
+#[derive(Debug)]
+pub struct EventStream(UnboundedReceiver<Event>);
+
+impl Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_next_unpin(cx)
+    }
+}
+
+/// Channels entering the application, each paired with the stream of its later events.
+///
+/// The channel stream is registered while the `StasisStart` is dispatched, so no event of the channel can be
+/// missed between receiving the `StasisStart` and subscribing.
+#[derive(Debug)]
+pub struct StasisStartStream(UnboundedReceiver<(StasisStart, EventStream)>);
+
+impl Stream for StasisStartStream {
+    type Item = (StasisStart, EventStream);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_next_unpin(cx)
+    }
+}
+
+/// What happens when a subscriber's queue is full and a new event arrives.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Wait for the subscriber to make room, pausing the WebSocket reader in the meantime.
+    Block,
+    /// Discard the oldest queued event to make room for the new one.
+    DropOldest,
+    /// Discard the new event.
+    #[default]
+    DropNewest,
+}
+
+#[derive(Debug)]
+struct Queue {
+    events: Mutex<VecDeque<Event>>,
+    capacity: usize,
+    receiver_closed: AtomicBool,
+    sender_closed: AtomicBool,
+    readable: Notify,
+    writable: Notify,
+}
+
+/// Receiving end of a subscription to every event received by the client.
+#[derive(Debug)]
+pub struct EventReceiver(Arc<Queue>);
+
+impl EventReceiver {
+    /// Wait for the next event, `None` once the client has been dropped and the queue is drained.
+    pub async fn recv(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.0.events.lock().unwrap().pop_front() {
+                self.0.writable.notify_one();
+                return Some(event);
+            }
+
+            if self.0.sender_closed.load(Ordering::Acquire) {
+                return None;
+            }
+
+            self.0.readable.notified().await;
+        }
+    }
+}
+
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        self.0.receiver_closed.store(true, Ordering::Release);
+        self.0.writable.notify_one();
+    }
+}
+
+#[derive(Debug)]
+struct Subscription {
+    resource: Resource,
+    tx: UnboundedSender<Event>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Dispatcher {
+    pub(crate) backpressure: Backpressure,
+    pub(crate) capacity: usize,
+    dropped: AtomicU64,
+    receivers: Mutex<Vec<Arc<Queue>>>,
+    subscriptions: Mutex<Vec<Subscription>>,
+    stasis_starts: Mutex<Vec<UnboundedSender<(StasisStart, EventStream)>>>,
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Self {
+            backpressure: Backpressure::default(),
+            capacity: 1024,
+            dropped: AtomicU64::new(0),
+            receivers: Mutex::new(Vec::new()),
+            subscriptions: Mutex::new(Vec::new()),
+            stasis_starts: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl Drop for Dispatcher {
+    fn drop(&mut self) {
+        for queue in self.receivers.lock().unwrap().iter() {
+            queue.sender_closed.store(true, Ordering::Release);
+            queue.readable.notify_one();
+        }
+    }
+}
+
+impl Dispatcher {
+    pub(crate) fn receiver(&self) -> EventReceiver {
+        let queue = Arc::new(Queue {
+            events: Mutex::new(VecDeque::with_capacity(self.capacity)),
+            capacity: self.capacity.max(1),
+            receiver_closed: AtomicBool::new(false),
+            sender_closed: AtomicBool::new(false),
+            readable: Notify::new(),
+            writable: Notify::new(),
+        });
+
+        self.receivers.lock().unwrap().push(queue.clone());
+        EventReceiver(queue)
+    }
+
+    pub(crate) fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn subscribe(&self, resource: Resource) -> EventStream {
+        let (tx, rx) = unbounded();
+        self.subscriptions.lock().unwrap().push(Subscription { resource, tx });
+        EventStream(rx)
+    }
+
+    pub(crate) async fn broadcast(&self, event: Event) {
+        let receivers = {
+            let mut receivers = self.receivers.lock().unwrap();
+            receivers.retain(|queue| !queue.receiver_closed.load(Ordering::Acquire));
+            receivers.clone()
+        };
+
+        for queue in receivers {
+            self.push(&queue, event.clone()).await;
+        }
+    }
+
+    async fn push(&self, queue: &Queue, event: Event) {
+        loop {
+            {
+                let mut events = queue.events.lock().unwrap();
+
+                if queue.receiver_closed.load(Ordering::Acquire) {
+                    return;
+                }
+
+                if events.len() < queue.capacity {
+                    events.push_back(event);
+                    break;
+                }
+
+                match self.backpressure {
+                    Backpressure::Block => {}
+                    Backpressure::DropOldest => {
+                        events.pop_front();
+                        events.push_back(event);
+                        self.record_drop();
+                        break;
+                    }
+                    Backpressure::DropNewest => {
+                        self.record_drop();
+                        return;
+                    }
+                }
+            }
+
+            queue.writable.notified().await;
+        }
+
+        queue.readable.notify_one();
+    }
+
+    fn record_drop(&self) {
+        let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+        event!(Level::WARN, "Subscriber queue full, dropped event ({} dropped in total)", dropped);
+    }
+
+    pub(crate) fn stasis_starts(&self) -> StasisStartStream {
+        let (tx, rx) = unbounded();
+        self.stasis_starts.lock().unwrap().push(tx);
+        StasisStartStream(rx)
+    }
+
+    pub(crate) fn dispatch(&self, event: &Event) {
+        self.subscriptions.lock().unwrap().retain(|subscription| {
+            if subscription.tx.is_closed() {
+                return false;
+            }
+
+            if !event.references(&subscription.resource) {
+                return true;
+            }
+
+            if subscription.tx.unbounded_send(event.clone()).is_err() {
+                return false;
+            }
+
+            !event.ends(&subscription.resource)
+        });
+
+        if let Event::StasisStart(stasis_start) = event {
+            self.stasis_starts.lock().unwrap().retain(|tx| {
+                if tx.is_closed() {
+                    return false;
+                }
+
+                let events = self.subscribe(Resource::Channel(stasis_start.channel.id.clone()));
+                tx.unbounded_send((stasis_start.clone(), events)).is_ok()
+            });
+        }
+    }
+}
+
+impl Event {
+    /// Channels carried by the event.
+    pub fn channels(&self) -> Vec<&Channel> {
+        match self {
+            Event::StasisStart(e) => vec![&e.channel],
+            Event::StasisEnd(e) => vec![&e.channel],
+            Event::ChannelCreated(e) => e.channel.iter().collect(),
+            Event::ChannelDestroyed(e) => vec![&e.channel],
+            Event::ChannelVarset(e) => e.channel.iter().collect(),
+            Event::ChannelHangupRequest(e) => vec![&e.channel],
+            Event::ChannelDialplan(e) => vec![&e.channel],
+            Event::ChannelStateChange(e) => vec![&e.channel],
+            Event::ChannelDtmfReceived(e) => vec![&e.channel],
+            Event::ChannelEnteredBridge(e) => e.channel.iter().collect(),
+            Event::ChannelLeftBridge(e) => vec![&e.channel],
+            Event::ChannelTalkingStarted(e) => vec![&e.channel],
+            Event::ChannelTalkingFinished(e) => vec![&e.channel],
+            Event::ChannelHold(e) => vec![&e.channel],
+            Event::ChannelUnhold(e) => vec![&e.channel],
+            Event::ChannelCallerId(e) => vec![&e.channel],
+            Event::ChannelConnectedLine(e) => vec![&e.channel],
+            Event::ChannelUserevent(e) => e.channel.iter().collect(),
+            Event::ChannelToneDetected(e) => vec![&e.channel],
+            Event::Dial(e) => e.caller.iter().chain([&e.peer]).chain(e.forwarded.iter()).collect(),
+            Event::BridgeBlindTransfer(e) => [&e.channel]
+                .into_iter()
+                .chain(e.replace_channel.iter())
+                .chain(e.transferee.iter())
+                .collect(),
+            Event::BridgeAttendedTransfer(e) => [&e.transferer_first_leg, &e.transferer_second_leg]
+                .into_iter()
+                .chain(e.replace_channel.iter())
+                .chain(e.transferee.iter())
+                .chain(e.transfer_target.iter())
+                .chain(e.destination_link_first_leg.iter())
+                .chain(e.destination_link_second_leg.iter())
+                .chain(e.destination_threeway_channel.iter())
+                .collect(),
+            Event::ApplicationMoveFailed(e) => vec![&e.channel],
+            _ => vec![],
+        }
+    }
+
+    /// Bridges carried by the event.
+    pub fn bridges(&self) -> Vec<&Bridge> {
+        match self {
+            Event::ChannelEnteredBridge(e) => vec![&e.bridge],
+            Event::ChannelLeftBridge(e) => vec![&e.bridge],
+            Event::ChannelUserevent(e) => e.bridge.iter().collect(),
+            Event::BridgeCreated(e) => vec![&e.bridge],
+            Event::BridgeDestroyed(e) => vec![&e.bridge],
+            Event::BridgeMerged(e) => vec![&e.bridge, &e.bridge_from],
+            Event::BridgeVideoSourceChanged(e) => vec![&e.bridge],
+            Event::BridgeBlindTransfer(e) => e.bridge.iter().collect(),
+            Event::BridgeAttendedTransfer(e) => e
+                .transferer_first_leg_bridge
+                .iter()
+                .chain(e.transferer_second_leg_bridge.iter())
+                .chain(e.destination_threeway_bridge.iter())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Endpoints carried by the event.
+    pub fn endpoints(&self) -> Vec<&Endpoint> {
+        match self {
+            Event::EndpointStateChange(e) => vec![&e.endpoint],
+            Event::ContactStatusChange(e) => vec![&e.endpoint],
+            Event::PeerStatusChange(e) => vec![&e.endpoint],
+            Event::TextMessageReceived(e) => e.endpoint.iter().collect(),
+            _ => vec![],
+        }
+    }
+
+    fn references(&self, resource: &Resource) -> bool {
+        match resource {
+            Resource::Channel(id) => self.channels().iter().any(|channel| &channel.id == id),
+            Resource::Bridge(id) => self.bridges().iter().any(|bridge| &bridge.id == id),
+            Resource::Playback(id) => match self {
+                Event::PlaybackStarted(e) => &e.playback.id == id,
+                Event::PlaybackContinuing(e) => &e.playback.id == id,
+                Event::PlaybackFinished(e) => &e.playback.id == id,
+                _ => false,
+            },
+            Resource::Recording(name) => match self {
+                Event::RecordingStarted(e) => &e.recording.name == name,
+                Event::RecordingFinished(e) => &e.recording.name == name,
+                Event::RecordingFailed(e) => &e.recording.name == name,
+                _ => false,
+            },
+            Resource::Endpoint(technology, resource) => self
+                .endpoints()
+                .iter()
+                .any(|endpoint| &endpoint.technology == technology && &endpoint.resource == resource),
+        }
+    }
+
+    fn ends(&self, resource: &Resource) -> bool {
+        match resource {
+            Resource::Channel(id) => match self {
+                Event::StasisEnd(e) => &e.channel.id == id,
+                Event::ChannelDestroyed(e) => &e.channel.id == id,
+                _ => false,
+            },
+            Resource::Bridge(id) => matches!(self, Event::BridgeDestroyed(e) if &e.bridge.id == id),
+            Resource::Playback(id) => matches!(self, Event::PlaybackFinished(e) if &e.playback.id == id),
+            Resource::Recording(name) => match self {
+                Event::RecordingFinished(e) => &e.recording.name == name,
+                Event::RecordingFailed(e) => &e.recording.name == name,
+                _ => false,
+            },
+            Resource::Endpoint(..) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::FutureExt;
+    use serde_json::json;
+    use tokio::time::timeout;
+
+    use super::*;
+    use crate::client::Client;
+
+    fn channel_event(event_type: &str, channel_id: &str, extra: serde_json::Value) -> Event {
+        let mut event = json!({
+            "type": event_type,
+            "timestamp": "2024-10-17T10:00:00.000+0000",
+            "channel": {
+                "id": channel_id,
+                "name": "PJSIP/1001-00000001",
+                "state": "Up",
+                "caller": {"name": "", "number": "1001"},
+                "connected": {"name": "", "number": ""},
+                "accountcode": "",
+                "dialplan": {"context": "default", "exten": "s", "priority": 1},
+                "creationtime": "2024-10-17T10:00:00.000+0000",
+                "language": "en"
+            },
+            "asterisk_id": "52:54:00:12:34:56",
+            "application": "ari"
+        });
+
+        if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+            event.extend(extra.clone());
+        }
+
+        serde_json::from_value(event).unwrap()
+    }
+
+    fn numbered_event(n: u64) -> Event {
+        Event::Unknown(json!({"type": "Test", "n": n}))
+    }
+
+    fn number(event: Option<Event>) -> Option<u64> {
+        match event {
+            Some(Event::Unknown(value)) => value["n"].as_u64(),
+            _ => None,
+        }
+    }
+
+    fn dispatcher(backpressure: Backpressure, capacity: usize) -> Arc<Dispatcher> {
+        let mut dispatcher = Dispatcher::default();
+        dispatcher.backpressure = backpressure;
+        dispatcher.capacity = capacity;
+        Arc::new(dispatcher)
+    }
+
+    #[tokio::test]
+    async fn blocked_producer_is_woken_by_recv() {
+        let dispatcher = dispatcher(Backpressure::Block, 1);
+        let mut receiver = dispatcher.receiver();
+
+        dispatcher.broadcast(numbered_event(1)).await;
+
+        let producer = tokio::spawn({
+            let dispatcher = dispatcher.clone();
+            async move { dispatcher.broadcast(numbered_event(2)).await }
+        });
+
+        tokio::task::yield_now().await;
+        assert!(!producer.is_finished());
+
+        assert_eq!(number(receiver.recv().await), Some(1));
+        timeout(Duration::from_secs(1), producer).await.unwrap().unwrap();
+        assert_eq!(number(receiver.recv().await), Some(2));
+        assert_eq!(dispatcher.dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn blocked_producer_is_released_when_receiver_is_dropped() {
+        let dispatcher = dispatcher(Backpressure::Block, 1);
+        let receiver = dispatcher.receiver();
+
+        dispatcher.broadcast(numbered_event(1)).await;
+
+        let producer = tokio::spawn({
+            let dispatcher = dispatcher.clone();
+            async move { dispatcher.broadcast(numbered_event(2)).await }
+        });
+
+        tokio::task::yield_now().await;
+        assert!(!producer.is_finished());
+
+        drop(receiver);
+        timeout(Duration::from_secs(1), producer).await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_latest_events() {
+        let dispatcher = dispatcher(Backpressure::DropOldest, 2);
+        let mut receiver = dispatcher.receiver();
+
+        for n in 1..=5 {
+            dispatcher.broadcast(numbered_event(n)).await;
+        }
+
+        assert_eq!(dispatcher.dropped(), 3);
+        assert_eq!(number(receiver.recv().await), Some(4));
+        assert_eq!(number(receiver.recv().await), Some(5));
+    }
+
+    #[tokio::test]
+    async fn drop_newest_keeps_earliest_events() {
+        let dispatcher = dispatcher(Backpressure::DropNewest, 2);
+        let mut receiver = dispatcher.receiver();
+
+        for n in 1..=5 {
+            dispatcher.broadcast(numbered_event(n)).await;
+        }
+
+        assert_eq!(dispatcher.dropped(), 3);
+        assert_eq!(number(receiver.recv().await), Some(1));
+        assert_eq!(number(receiver.recv().await), Some(2));
+    }
+
+    #[tokio::test]
+    async fn slow_receiver_does_not_affect_others() {
+        let dispatcher = dispatcher(Backpressure::DropNewest, 1);
+        let mut slow = dispatcher.receiver();
+        let mut fast = dispatcher.receiver();
+
+        dispatcher.broadcast(numbered_event(1)).await;
+        assert_eq!(number(fast.recv().await), Some(1));
+        dispatcher.broadcast(numbered_event(2)).await;
+
+        assert_eq!(dispatcher.dropped(), 1);
+        assert_eq!(number(fast.recv().await), Some(2));
+        assert_eq!(number(slow.recv().await), Some(1));
+    }
+
+    #[tokio::test]
+    async fn recv_returns_none_after_client_is_dropped() {
+        let client = Client::new().build().unwrap();
+        let mut receiver = client.subscribe();
+
+        client.handle_message(br#"{"type":"Test","n":1}"#.to_vec()).await;
+        drop(client);
+
+        assert_eq!(number(receiver.recv().await), Some(1));
+        assert!(timeout(Duration::from_secs(1), receiver.recv()).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn stasis_start_stream_receives_events_dispatched_before_polling() {
+        let dispatcher = Dispatcher::default();
+        let mut stasis_starts = dispatcher.stasis_starts();
+
+        dispatcher.dispatch(&channel_event("StasisStart", "1", json!({"args": []})));
+        dispatcher.dispatch(&channel_event(
+            "ChannelDtmfReceived",
+            "1",
+            json!({"digit": "5", "duration_ms": 100}),
+        ));
+        dispatcher.dispatch(&channel_event("StasisEnd", "1", json!({})));
+
+        let (stasis_start, mut events) = stasis_starts.next().now_or_never().flatten().unwrap();
+        assert_eq!(stasis_start.channel.id, "1");
+
+        assert!(matches!(events.next().now_or_never(), Some(Some(Event::ChannelDtmfReceived(e))) if e.digit == "5"));
+        assert!(matches!(events.next().now_or_never(), Some(Some(Event::StasisEnd(_)))));
+        assert!(matches!(events.next().now_or_never(), Some(None)));
+    }
+}
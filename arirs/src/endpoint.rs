@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::{event, instrument, Level};
+
+use crate::{client::Client, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Endpoint {
+    pub technology: String,
+    pub resource: String,
+    pub state: Option<EndpointState>,
+    pub channel_ids: Vec<String>,
+}
+
+impl Endpoint {
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client) -> Result<Vec<Endpoint>> {
+        let url = client.url.join("endpoints")?;
+
+        let endpoints = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Endpoint>>()
+            .await?;
+        event!(Level::INFO, "received endpoints");
+        Ok(endpoints)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn list_by_technology(client: &Client, technology: &str) -> Result<Vec<Endpoint>> {
+        let url = client.url.join(&format!("endpoints/{}", technology))?;
+
+        let endpoints = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Endpoint>>()
+            .await?;
+        event!(Level::INFO, "received {} endpoints", technology);
+        Ok(endpoints)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, technology: &str, resource: &str) -> Result<Endpoint> {
+        let url = client.url.join(&format!("endpoints/{}/{}", technology, resource))?;
+
+        let endpoint = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Endpoint>()
+            .await?;
+        event!(Level::INFO, "received endpoint {}/{}", technology, resource);
+        Ok(endpoint)
+    }
+
+    /// Send a message to `to`, a technology specific URI such as `pjsip:alice`.
+    #[instrument(level = "debug")]
+    pub async fn send_message(client: &Client, to: &str, from: &str, body: Option<&str>, variables: HashMap<&str, &str>) -> Result<()> {
+        let mut url = client.url.join("endpoints/sendMessage")?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("to", to).append_pair("from", from);
+
+            if let Some(body) = body {
+                query.append_pair("body", body);
+            }
+        }
+
+        let body = json!({
+            "variables": variables
+        });
+
+        client.put(url).json(&body).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "sent message to {}", to);
+        Ok(())
+    }
+
+    /// Send a message to this endpoint.
+    #[instrument(level = "debug")]
+    pub async fn send_message_to_endpoint(
+        &self,
+        client: &Client,
+        from: &str,
+        body: Option<&str>,
+        variables: HashMap<&str, &str>,
+    ) -> Result<()> {
+        let mut url = client
+            .url
+            .join(&format!("endpoints/{}/{}/sendMessage", self.technology, self.resource))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("from", from);
+
+            if let Some(body) = body {
+                query.append_pair("body", body);
+            }
+        }
+
+        let body = json!({
+            "variables": variables
+        });
+
+        client.put(url).json(&body).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "sent message to endpoint {}/{}", self.technology, self.resource);
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointState {
+    #[display("unknown")]
+    Unknown,
+    #[display("offline")]
+    Offline,
+    #[display("online")]
+    Online,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ContactInfo {
+    pub uri: String,
+    pub contact_status: String,
+    pub aor: String,
+    pub roundtrip_usec: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Peer {
+    pub peer_status: String,
+    pub cause: Option<String>,
+    pub address: Option<String>,
+    pub port: Option<String>,
+    pub time: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct TextMessage {
+    pub from: String,
+    pub to: String,
+    pub body: String,
+    pub variables: Option<HashMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct EndpointStateChange {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub endpoint: Endpoint,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ContactStatusChange {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub endpoint: Endpoint,
+    pub contact_info: ContactInfo,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct PeerStatusChange {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub endpoint: Endpoint,
+    pub peer: Peer,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct TextMessageReceived {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub message: TextMessage,
+    pub endpoint: Option<Endpoint>,
+    pub asterisk_id: String,
+    pub application: String,
+}
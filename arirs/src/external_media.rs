@@ -0,0 +1,324 @@
+use std::{io, net::SocketAddr, sync::Mutex};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use derive_more::Display;
+use futures_util::{stream, Stream};
+use tokio::net::{ToSocketAddrs, UdpSocket};
+use tracing::{event, instrument, Level};
+
+use crate::Result;
+
+const RTP_VERSION: u8 = 2;
+const RTP_HEADER_LEN: usize = 12;
+const MAX_DATAGRAM_LEN: usize = 1500;
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum Encapsulation {
+    #[display("rtp")]
+    Rtp,
+    #[display("audiosocket")]
+    AudioSocket,
+}
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    #[display("udp")]
+    Udp,
+    #[display("tcp")]
+    Tcp,
+}
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionType {
+    #[display("client")]
+    Client,
+    #[display("server")]
+    Server,
+}
+
+/// A single RTP packet exchanged with an external media channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtpFrame {
+    pub payload_type: u8,
+    pub marker: bool,
+    pub sequence: u16,
+    pub timestamp: u32,
+    pub ssrc: u32,
+    pub payload: Bytes,
+}
+
+impl RtpFrame {
+    /// Parse an RTP packet, skipping CSRCs, header extensions and padding. `None` if it is not valid RTP.
+    pub fn parse(packet: &[u8]) -> Option<RtpFrame> {
+        if packet.len() < RTP_HEADER_LEN || packet[0] >> 6 != RTP_VERSION {
+            return None;
+        }
+
+        let padding = packet[0] & 0x20 != 0;
+        let extension = packet[0] & 0x10 != 0;
+        let csrc_count = (packet[0] & 0x0f) as usize;
+
+        let mut offset = RTP_HEADER_LEN + csrc_count * 4;
+
+        if extension {
+            let length = packet.get(offset + 2..offset + 4)?;
+            offset += 4 + u16::from_be_bytes([length[0], length[1]]) as usize * 4;
+        }
+
+        let mut end = packet.len();
+
+        if padding {
+            // the padding length counts itself, so it is at least 1
+            let padding_len = *packet.last()? as usize;
+            if padding_len == 0 {
+                return None;
+            }
+
+            end = end.checked_sub(padding_len)?;
+        }
+
+        if offset > end {
+            return None;
+        }
+
+        Some(RtpFrame {
+            payload_type: packet[1] & 0x7f,
+            marker: packet[1] & 0x80 != 0,
+            sequence: u16::from_be_bytes([packet[2], packet[3]]),
+            timestamp: u32::from_be_bytes([packet[4], packet[5], packet[6], packet[7]]),
+            ssrc: u32::from_be_bytes([packet[8], packet[9], packet[10], packet[11]]),
+            payload: Bytes::copy_from_slice(&packet[offset..end]),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut packet = BytesMut::with_capacity(RTP_HEADER_LEN + self.payload.len());
+        packet.put_u8(RTP_VERSION << 6);
+        packet.put_u8((self.marker as u8) << 7 | (self.payload_type & 0x7f));
+        packet.put_u16(self.sequence);
+        packet.put_u32(self.timestamp);
+        packet.put_u32(self.ssrc);
+        packet.put_slice(&self.payload);
+        packet.freeze()
+    }
+}
+
+#[derive(Debug)]
+struct Peer {
+    address: SocketAddr,
+    payload_type: u8,
+}
+
+#[derive(Debug)]
+struct Outgoing {
+    sequence: u16,
+    timestamp: u32,
+    ssrc: u32,
+}
+
+/// Local UDP socket receiving the RTP stream of an external media channel and sending audio back.
+///
+/// Pass [`RtpEndpoint::external_host`] as the `external_host` of an [`ExternalMediaRequest`](crate::channel::ExternalMediaRequest).
+/// Audio can only be sent back once the first packet from Asterisk has been received, its source address and
+/// payload type are reused for the outgoing stream.
+#[derive(Debug)]
+pub struct RtpEndpoint {
+    socket: UdpSocket,
+    peer: Mutex<Option<Peer>>,
+    outgoing: Mutex<Outgoing>,
+}
+
+impl RtpEndpoint {
+    #[instrument(level = "debug", skip(address))]
+    pub async fn bind(address: impl ToSocketAddrs) -> Result<RtpEndpoint> {
+        let socket = UdpSocket::bind(address).await?;
+        event!(Level::INFO, "bound RTP endpoint to {}", socket.local_addr()?);
+
+        Ok(RtpEndpoint {
+            socket,
+            peer: Mutex::new(None),
+            outgoing: Mutex::new(Outgoing {
+                sequence: rand::random(),
+                timestamp: rand::random(),
+                ssrc: rand::random(),
+            }),
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// The `host:port` Asterisk should send media to.
+    ///
+    /// Fails when bound to a wildcard address such as `0.0.0.0`, which Asterisk cannot send to, use
+    /// [`RtpEndpoint::local_addr`] with the host's reachable address instead.
+    pub fn external_host(&self) -> Result<String> {
+        let address = self.local_addr()?;
+
+        if address.ip().is_unspecified() {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrNotAvailable,
+                format!("RTP endpoint is bound to the wildcard address {}", address),
+            )
+            .into());
+        }
+
+        Ok(address.to_string())
+    }
+
+    /// Address Asterisk sends media from, once the first packet has been received.
+    pub fn remote_addr(&self) -> Option<SocketAddr> {
+        self.peer.lock().unwrap().as_ref().map(|peer| peer.address)
+    }
+
+    /// Wait for the next RTP frame, datagrams that are not RTP are skipped.
+    pub async fn recv(&self) -> Result<RtpFrame> {
+        let mut buffer = [0; MAX_DATAGRAM_LEN];
+
+        loop {
+            let (len, address) = self.socket.recv_from(&mut buffer).await?;
+
+            let Some(frame) = RtpFrame::parse(&buffer[..len]) else {
+                event!(Level::DEBUG, "ignoring non RTP datagram from {}", address);
+                continue;
+            };
+
+            let mut peer = self.peer.lock().unwrap();
+            if peer.as_ref().map(|peer| peer.address) != Some(address) {
+                event!(Level::INFO, "receiving RTP from {}", address);
+            }
+            *peer = Some(Peer {
+                address,
+                payload_type: frame.payload_type,
+            });
+
+            return Ok(frame);
+        }
+    }
+
+    /// Stream of the frames received from Asterisk.
+    pub fn frames(&self) -> impl Stream<Item = Result<RtpFrame>> + '_ {
+        stream::unfold(self, |endpoint| async move { Some((endpoint.recv().await, endpoint)) })
+    }
+
+    /// Send `payload` to Asterisk, advancing the RTP timestamp by `samples`.
+    pub async fn send(&self, payload: &[u8], samples: u32) -> Result<()> {
+        let Some((address, payload_type)) = self.peer.lock().unwrap().as_ref().map(|peer| (peer.address, peer.payload_type)) else {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "no RTP received from Asterisk yet").into());
+        };
+
+        let frame = {
+            let mut outgoing = self.outgoing.lock().unwrap();
+            let frame = RtpFrame {
+                payload_type,
+                marker: false,
+                sequence: outgoing.sequence,
+                timestamp: outgoing.timestamp,
+                ssrc: outgoing.ssrc,
+                payload: Bytes::copy_from_slice(payload),
+            };
+            outgoing.sequence = outgoing.sequence.wrapping_add(1);
+            outgoing.timestamp = outgoing.timestamp.wrapping_add(samples);
+            frame
+        };
+
+        self.socket.send_to(&frame.to_bytes(), address).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: [u8; 12] = [0x80, 0x76, 0x12, 0x34, 0x00, 0x00, 0x01, 0x40, 0xde, 0xad, 0xbe, 0xef];
+
+    fn packet(first_byte: u8, extra_header: &[u8], payload: &[u8], padding: &[u8]) -> Vec<u8> {
+        let mut packet = HEADER.to_vec();
+        packet[0] = first_byte;
+        packet.extend_from_slice(extra_header);
+        packet.extend_from_slice(payload);
+        packet.extend_from_slice(padding);
+        packet
+    }
+
+    #[test]
+    fn parse_header() {
+        let frame = RtpFrame::parse(&packet(0x80, &[], b"audio", &[])).unwrap();
+
+        assert_eq!(frame.payload_type, 118);
+        assert!(!frame.marker);
+        assert_eq!(frame.sequence, 0x1234);
+        assert_eq!(frame.timestamp, 320);
+        assert_eq!(frame.ssrc, 0xdeadbeef);
+        assert_eq!(frame.payload, &b"audio"[..]);
+    }
+
+    #[test]
+    fn parse_skips_csrcs() {
+        let frame = RtpFrame::parse(&packet(0x82, &[0; 8], b"audio", &[])).unwrap();
+
+        assert_eq!(frame.payload, &b"audio"[..]);
+    }
+
+    #[test]
+    fn parse_skips_header_extension() {
+        let extension = [0xbe, 0xde, 0x00, 0x02, 1, 2, 3, 4, 5, 6, 7, 8];
+        let frame = RtpFrame::parse(&packet(0x90, &extension, b"audio", &[])).unwrap();
+        assert_eq!(frame.payload, &b"audio"[..]);
+    }
+
+    #[test]
+    fn parse_strips_padding() {
+        let frame = RtpFrame::parse(&packet(0xa0, &[], b"audio", &[0, 0, 3])).unwrap();
+
+        assert_eq!(frame.payload, &b"audio"[..]);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_padding() {
+        assert!(RtpFrame::parse(&packet(0xa0, &[], b"audio", &[0])).is_none());
+        assert!(RtpFrame::parse(&packet(0xa0, &[], b"", &[0, 20])).is_none());
+    }
+
+    #[test]
+    fn parse_rejects_truncated_packets() {
+        let full = packet(0x92, &[0; 8], b"", &[]);
+
+        for len in 0..full.len() {
+            assert!(RtpFrame::parse(&full[..len]).is_none(), "accepted {} bytes", len);
+        }
+
+        assert!(RtpFrame::parse(&packet(0x90, &[0xbe, 0xde], b"", &[])).is_none());
+        assert!(RtpFrame::parse(&packet(0x90, &[0xbe, 0xde, 0x00, 0x01], b"", &[])).is_none());
+    }
+
+    #[test]
+    fn parse_rejects_other_versions() {
+        assert!(RtpFrame::parse(&packet(0x40, &[], b"audio", &[])).is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        let frame = RtpFrame {
+            payload_type: 0,
+            marker: true,
+            sequence: u16::MAX,
+            timestamp: u32::MAX,
+            ssrc: 42,
+            payload: Bytes::from_static(b"audio"),
+        };
+
+        assert_eq!(RtpFrame::parse(&frame.to_bytes()), Some(frame));
+    }
+
+    #[tokio::test]
+    async fn external_host_rejects_wildcard_address() {
+        let endpoint = RtpEndpoint::bind("0.0.0.0:0").await.unwrap();
+        assert!(endpoint.external_host().is_err());
+
+        let endpoint = RtpEndpoint::bind("127.0.0.1:0").await.unwrap();
+        assert!(endpoint.external_host().unwrap().starts_with("127.0.0.1:"));
+    }
+}
@@ -1,20 +1,35 @@
+use application::ApplicationReplaced;
+use bridge::{BridgeAttendedTransfer, BridgeBlindTransfer, BridgeCreated, BridgeDestroyed, BridgeMerged, BridgeVideoSourceChanged};
 use channel::{
-    ChannelCreated, ChannelDestroyed, ChannelDialplan, ChannelDtmfReceived, ChannelHangupRequest, ChannelStateChange, ChannelVarset,
-    StasisEnd, StasisStart,
+    ApplicationMoveFailed, ChannelCallerId, ChannelConnectedLine, ChannelCreated, ChannelDestroyed, ChannelDialplan, ChannelDtmfReceived,
+    ChannelEnteredBridge, ChannelHangupRequest, ChannelHold, ChannelLeftBridge, ChannelStateChange, ChannelTalkingFinished,
+    ChannelTalkingStarted, ChannelToneDetected, ChannelUnhold, ChannelUserevent, ChannelVarset, Dial, StasisEnd, StasisStart,
 };
+use client::ConnectionEvent;
 use device::DeviceStateChanged;
-use serde::{Deserialize, Serialize};
+use endpoint::{ContactStatusChange, EndpointStateChange, PeerStatusChange, TextMessageReceived};
+use playback::{PlaybackContinuing, PlaybackFinished, PlaybackStarted};
+use recording::{RecordingFailed, RecordingFinished, RecordingStarted};
+use reqwest::StatusCode;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 use tokio::task::JoinError;
 use tokio_tungstenite::tungstenite;
 
+pub mod application;
+pub mod asterisk;
 pub mod bridge;
 pub mod channel;
 pub mod client;
 pub mod device;
+pub mod dispatcher;
+pub mod endpoint;
+pub mod external_media;
+pub mod mailbox;
 pub mod playback;
 pub mod recording;
 pub mod rtp_statistics;
+pub mod sound;
 pub mod variable;
 
 pub type Result<T> = std::result::Result<T, AriError>;
@@ -24,23 +39,114 @@ pub enum AriError {
     #[error("URL parsing error")]
     UrlParseError(#[from] url::ParseError),
     #[error("WebSocket error")]
-    TungsteniteError(#[from] tungstenite::Error),
+    TungsteniteError(#[from] Box<tungstenite::Error>),
     #[error("HTTP Request error")]
     ReqwestError(#[from] reqwest::Error),
     #[error("Join Error")]
     JoinError(#[from] JoinError),
+    #[error("I/O error")]
+    IoError(#[from] std::io::Error),
+    #[error("TLS error")]
+    TlsError(#[from] native_tls::Error),
+    #[error("Bad request ({status}): {message}")]
+    BadRequest { status: StatusCode, message: String },
+    #[error("Unauthorized ({status}): {message}")]
+    Unauthorized { status: StatusCode, message: String },
+    #[error("Not found ({status}): {message}")]
+    NotFound { status: StatusCode, message: String },
+    #[error("Conflict ({status}): {message}")]
+    Conflict { status: StatusCode, message: String },
+    #[error("Precondition failed ({status}): {message}")]
+    PreconditionFailed { status: StatusCode, message: String },
+    #[error("Server error ({status}): {message}")]
+    ServerError { status: StatusCode, message: String },
+    #[error("Variable '{0}' not found")]
+    VariableNotFound(String),
+    #[error("Recording '{name}' failed: {}", cause.as_deref().unwrap_or("unknown cause"))]
+    RecordingFailed { name: String, cause: Option<String> },
+    #[error("Channel '{0}' ended before entering the application")]
+    ChannelEnded(String),
+    #[error("Channel '{0}' did not enter the application in time")]
+    StasisStartTimeout(String),
+    #[error("Application '{0}' is not registered by this client")]
+    UnregisteredApplication(String),
     #[error("Unknown error occurred: {0}")]
     Unknown(String),
 }
 
+impl AriError {
+    /// The HTTP status code returned by Asterisk, if the error originates from a REST response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            AriError::BadRequest { status, .. }
+            | AriError::Unauthorized { status, .. }
+            | AriError::NotFound { status, .. }
+            | AriError::Conflict { status, .. }
+            | AriError::PreconditionFailed { status, .. }
+            | AriError::ServerError { status, .. } => Some(*status),
+            AriError::ReqwestError(err) => err.status(),
+            _ => None,
+        }
+    }
+
+    async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        AriError::from_status(status, body)
+    }
+
+    fn from_status(status: StatusCode, body: String) -> Self {
+        let message = match serde_json::from_str::<ErrorResponse>(&body) {
+            Ok(error) => error.message,
+            Err(_) => body,
+        };
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => AriError::Unauthorized { status, message },
+            StatusCode::NOT_FOUND => AriError::NotFound { status, message },
+            StatusCode::CONFLICT => AriError::Conflict { status, message },
+            StatusCode::PRECONDITION_FAILED => AriError::PreconditionFailed { status, message },
+            status if status.is_server_error() => AriError::ServerError { status, message },
+            _ => AriError::BadRequest { status, message },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+pub(crate) trait ResponseExt: Sized {
+    /// Turn a non-2xx response into the matching [`AriError`], keeping the message sent by Asterisk.
+    async fn ari_error_for_status(self) -> Result<Self>;
+}
+
+impl ResponseExt for reqwest::Response {
+    async fn ari_error_for_status(self) -> Result<Self> {
+        if self.status().is_success() {
+            Ok(self)
+        } else {
+            Err(AriError::from_response(self).await)
+        }
+    }
+}
+
+impl From<tungstenite::Error> for AriError {
+    fn from(err: tungstenite::Error) -> Self {
+        AriError::TungsteniteError(Box::new(err))
+    }
+}
+
 impl From<tungstenite::error::UrlError> for AriError {
     fn from(err: tungstenite::error::UrlError) -> Self {
-        AriError::TungsteniteError(err.into())
+        AriError::TungsteniteError(Box::new(err.into()))
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(remote = "Self", tag = "type")]
 pub enum Event {
     StasisStart(StasisStart),
     StasisEnd(StasisEnd),
@@ -51,7 +157,177 @@ pub enum Event {
     ChannelDialplan(ChannelDialplan),
     ChannelStateChange(ChannelStateChange),
     ChannelDtmfReceived(ChannelDtmfReceived),
+    ChannelEnteredBridge(ChannelEnteredBridge),
+    ChannelLeftBridge(ChannelLeftBridge),
+    ChannelTalkingStarted(ChannelTalkingStarted),
+    ChannelTalkingFinished(ChannelTalkingFinished),
+    ChannelHold(ChannelHold),
+    ChannelUnhold(ChannelUnhold),
+    ChannelCallerId(ChannelCallerId),
+    ChannelConnectedLine(ChannelConnectedLine),
+    ChannelUserevent(ChannelUserevent),
+    ChannelToneDetected(ChannelToneDetected),
+    Dial(Dial),
+    PlaybackStarted(PlaybackStarted),
+    PlaybackContinuing(PlaybackContinuing),
+    PlaybackFinished(PlaybackFinished),
+    RecordingStarted(RecordingStarted),
+    RecordingFinished(RecordingFinished),
+    RecordingFailed(RecordingFailed),
+    BridgeCreated(BridgeCreated),
+    BridgeDestroyed(BridgeDestroyed),
+    BridgeMerged(BridgeMerged),
+    BridgeVideoSourceChanged(BridgeVideoSourceChanged),
+    BridgeBlindTransfer(BridgeBlindTransfer),
+    BridgeAttendedTransfer(BridgeAttendedTransfer),
     DeviceStateChanged(DeviceStateChanged),
-    #[serde(other)]
-    Unknown,
+    EndpointStateChange(EndpointStateChange),
+    ContactStatusChange(ContactStatusChange),
+    PeerStatusChange(PeerStatusChange),
+    TextMessageReceived(TextMessageReceived),
+    ApplicationReplaced(ApplicationReplaced),
+    ApplicationMoveFailed(ApplicationMoveFailed),
+    #[serde(skip)]
+    Connection(ConnectionEvent),
+    /// Any event without a typed representation, kept as received.
+    ///
+    /// A typed event whose payload does not match its representation is a deserialization error instead.
+    #[serde(skip)]
+    Unknown(serde_json::Value),
+}
+
+impl Serialize for Event {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Event::Unknown(value) => value.serialize(serializer),
+            _ => Event::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        let Some(event_type) = value["type"].as_str().filter(|event_type| TYPED_EVENTS.contains(event_type)) else {
+            return Ok(Event::Unknown(value));
+        };
+
+        let event_type = event_type.to_string();
+        Event::deserialize(value).map_err(|e| de::Error::custom(format!("invalid {} event: {}", event_type, e)))
+    }
+}
+
+/// Event types with a typed [`Event`] variant, all others are deserialized as [`Event::Unknown`].
+const TYPED_EVENTS: &[&str] = &[
+    "StasisStart",
+    "StasisEnd",
+    "ChannelCreated",
+    "ChannelDestroyed",
+    "ChannelVarset",
+    "ChannelHangupRequest",
+    "ChannelDialplan",
+    "ChannelStateChange",
+    "ChannelDtmfReceived",
+    "ChannelEnteredBridge",
+    "ChannelLeftBridge",
+    "ChannelTalkingStarted",
+    "ChannelTalkingFinished",
+    "ChannelHold",
+    "ChannelUnhold",
+    "ChannelCallerId",
+    "ChannelConnectedLine",
+    "ChannelUserevent",
+    "ChannelToneDetected",
+    "Dial",
+    "PlaybackStarted",
+    "PlaybackContinuing",
+    "PlaybackFinished",
+    "RecordingStarted",
+    "RecordingFinished",
+    "RecordingFailed",
+    "BridgeCreated",
+    "BridgeDestroyed",
+    "BridgeMerged",
+    "BridgeVideoSourceChanged",
+    "BridgeBlindTransfer",
+    "BridgeAttendedTransfer",
+    "DeviceStateChanged",
+    "EndpointStateChange",
+    "ContactStatusChange",
+    "PeerStatusChange",
+    "TextMessageReceived",
+    "ApplicationReplaced",
+    "ApplicationMoveFailed",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_error_responses() {
+        let cases = [
+            (StatusCode::BAD_REQUEST, "BadRequest"),
+            (StatusCode::UNAUTHORIZED, "Unauthorized"),
+            (StatusCode::FORBIDDEN, "Unauthorized"),
+            (StatusCode::NOT_FOUND, "NotFound"),
+            (StatusCode::CONFLICT, "Conflict"),
+            (StatusCode::PRECONDITION_FAILED, "PreconditionFailed"),
+            (StatusCode::UNPROCESSABLE_ENTITY, "BadRequest"),
+            (StatusCode::INTERNAL_SERVER_ERROR, "ServerError"),
+            (StatusCode::SERVICE_UNAVAILABLE, "ServerError"),
+        ];
+
+        for (status, variant) in cases {
+            let error = AriError::from_status(status, r#"{"message":"Channel not in Stasis application"}"#.to_string());
+
+            assert!(format!("{:?}", error).starts_with(variant), "{}: {:?}", status, error);
+            assert_eq!(error.status(), Some(status));
+            assert!(error.to_string().ends_with(": Channel not in Stasis application"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn keep_non_json_error_body_as_message() {
+        let error = AriError::from_status(StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>".to_string());
+
+        assert!(matches!(error, AriError::ServerError { message, .. } if message == "<html>Bad Gateway</html>"));
+    }
+
+    #[test]
+    fn deserialize_unknown_event_type() {
+        let event: Event = serde_json::from_str(r#"{"type":"ChannelFooBar","timestamp":"2024-10-17T10:00:00.000+0000"}"#).unwrap();
+
+        assert!(matches!(event, Event::Unknown(value) if value["type"] == "ChannelFooBar"));
+    }
+
+    #[test]
+    fn reject_invalid_typed_event() {
+        let error = serde_json::from_str::<Event>(r#"{"type":"StasisEnd","timestamp":"bad"}"#).unwrap_err();
+
+        assert!(error.to_string().starts_with("invalid StasisEnd event"), "{}", error);
+    }
+
+    #[test]
+    fn skipped_variants_are_not_typed() {
+        // serde lists the variants it accepts when it meets an unknown tag
+        let error = Event::deserialize(serde_json::json!({"type": "NoSuchEvent"}))
+            .unwrap_err()
+            .to_string();
+        let (_, expected) = error.split_once("expected one of ").unwrap();
+        let variants = expected.split(", ").map(|variant| variant.trim_matches('`')).collect::<Vec<_>>();
+
+        assert_eq!(variants, TYPED_EVENTS);
+        assert!(!TYPED_EVENTS.contains(&"Connection"));
+        assert!(!TYPED_EVENTS.contains(&"Unknown"));
+    }
+
+    #[test]
+    fn serialize_unknown_event_as_received() {
+        let payload = r#"{"type":"ChannelFooBar","value":1}"#;
+        let event: Event = serde_json::from_str(payload).unwrap();
+
+        assert_eq!(serde_json::to_string(&event).unwrap(), payload);
+    }
 }
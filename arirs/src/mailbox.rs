@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use tracing::{event, instrument, Level};
+
+use crate::{client::Client, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Mailbox {
+    pub name: String,
+    pub old_messages: u32,
+    pub new_messages: u32,
+}
+
+impl Mailbox {
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client) -> Result<Vec<Mailbox>> {
+        let url = client.url.join("mailboxes")?;
+
+        let mailboxes = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Mailbox>>()
+            .await?;
+        event!(Level::INFO, "received mailboxes");
+        Ok(mailboxes)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, mailbox_name: &str) -> Result<Mailbox> {
+        let url = client.url.join(&format!("mailboxes/{}", mailbox_name))?;
+
+        let mailbox = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Mailbox>()
+            .await?;
+        event!(Level::INFO, "received mailbox {}", mailbox_name);
+        Ok(mailbox)
+    }
+
+    /// Change the message counts of a mailbox, creating it if needed.
+    #[instrument(level = "debug")]
+    pub async fn update(client: &Client, mailbox_name: &str, old_messages: u32, new_messages: u32) -> Result<()> {
+        let url = client
+            .url
+            .join(&format!("mailboxes/{}", mailbox_name))?
+            .query_pairs_mut()
+            .append_pair("oldMessages", &old_messages.to_string())
+            .append_pair("newMessages", &new_messages.to_string())
+            .finish()
+            .to_owned();
+
+        client.put(url).send().await?.ari_error_for_status().await?;
+        event!(
+            Level::INFO,
+            "updated mailbox {} to {} old and {} new messages",
+            mailbox_name,
+            old_messages,
+            new_messages
+        );
+        Ok(())
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn delete(client: &Client, mailbox_name: &str) -> Result<()> {
+        let url = client.url.join(&format!("mailboxes/{}", mailbox_name))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "deleted mailbox {}", mailbox_name);
+        Ok(())
+    }
+}
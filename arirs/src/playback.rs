@@ -1,33 +1,147 @@
+use chrono::DateTime;
 use derive_more::Display;
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
-use tracing::instrument;
+use tracing::{event, instrument, Level};
 
-use crate::{client::Client, Result};
+use crate::{client::Client, AriError, Event, ResponseExt, Result};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct Playback {
     pub id: String,
+    pub media_uri: String,
+    pub next_media_uri: Option<String>,
+    pub target_uri: String,
+    pub language: Option<String>,
+    pub state: PlaybackState,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackState {
+    #[display("queued")]
+    Queued,
+    #[display("playing")]
+    Playing,
+    #[display("paused")]
+    Paused,
+    #[display("continuing")]
+    Continuing,
+    #[display("done")]
+    Done,
+    #[display("failed")]
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct PlaybackStarted {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub playback: Playback,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct PlaybackContinuing {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub playback: Playback,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct PlaybackFinished {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub playback: Playback,
+    pub asterisk_id: String,
+    pub application: String,
 }
 
 impl Playback {
     #[instrument(level = "debug")]
-    pub async fn get_playback(_client: &Client, _playback_id: &str) -> Result<Playback> {
-        unimplemented!()
+    pub async fn get_playback(client: &Client, playback_id: &str) -> Result<Playback> {
+        let url = client.url.join(&format!("playbacks/{}", playback_id))?;
+
+        let playback = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Playback>()
+            .await?;
+
+        event!(Level::INFO, "received playback with id {}", playback.id);
+        Ok(playback)
     }
 
     #[instrument(level = "debug")]
-    pub async fn control(&self, _client: &Client, _operation: Operation) -> Result<()> {
-        unimplemented!()
+    pub async fn control(&self, client: &Client, operation: Operation) -> Result<()> {
+        let url = client
+            .url
+            .join(&format!("playbacks/{}/control", self.id))?
+            .query_pairs_mut()
+            .append_pair("operation", &format!("{}", operation))
+            .finish()
+            .to_owned();
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "applied {} to playback with id {}", operation, self.id);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn stop(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn stop(&self, client: &Client) -> Result<()> {
+        let url = client.url.join(&format!("playbacks/{}", self.id))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "stopped playback with id {}", self.id);
+        Ok(())
+    }
+
+    /// Wait until Asterisk reports the playback as finished and return its final state.
+    ///
+    /// A playback that has already finished and is no longer known to Asterisk resolves immediately.
+    /// `Client::run` must be running for the `PlaybackFinished` event to arrive.
+    #[instrument(level = "debug")]
+    pub async fn finished(&self, client: &Client) -> Result<Playback> {
+        if matches!(self.state, PlaybackState::Done | PlaybackState::Failed) {
+            return Ok(self.clone());
+        }
+
+        let mut events = client.playback_events(&self.id);
+
+        match Playback::get_playback(client, &self.id).await {
+            Ok(playback) if matches!(playback.state, PlaybackState::Done | PlaybackState::Failed) => return Ok(playback),
+            Ok(_) => {}
+            Err(AriError::NotFound { .. }) => {
+                return Ok(Playback {
+                    state: PlaybackState::Done,
+                    ..self.clone()
+                })
+            }
+            Err(e) => return Err(e),
+        }
+
+        while let Some(event) = events.next().await {
+            if let Event::PlaybackFinished(e) = event {
+                event!(Level::INFO, "playback with id {} finished", self.id);
+                return Ok(e.playback);
+            }
+        }
+
+        Err(AriError::Unknown(format!(
+            "event stream closed before playback {} finished",
+            self.id
+        )))
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Display)]
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum Operation {
     #[display("restart")]
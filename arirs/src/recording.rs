@@ -1,78 +1,317 @@
+use bytes::Bytes;
+use chrono::DateTime;
+use derive_more::Display;
+use futures_util::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use tracing::instrument;
+use tracing::{event, instrument, Level};
+use url::Url;
 
-use crate::{client::Client, Result};
+use crate::{client::Client, dispatcher::EventStream, AriError, Event, ResponseExt, Result};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct LiveRecording {
-    pub id: String,
     pub name: String,
+    pub format: String,
+    pub target_uri: String,
+    pub state: RecordingState,
+    pub duration: Option<i32>,
+    pub talking_duration: Option<i32>,
+    pub silence_duration: Option<i32>,
+    pub cause: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingState {
+    #[display("queued")]
+    Queued,
+    #[display("recording")]
+    Recording,
+    #[display("paused")]
+    Paused,
+    #[display("done")]
+    Done,
+    #[display("failed")]
+    Failed,
+    #[display("canceled")]
+    Canceled,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct RecordingStarted {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub recording: LiveRecording,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct RecordingFinished {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub recording: LiveRecording,
+    pub asterisk_id: String,
+    pub application: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct RecordingFailed {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub recording: LiveRecording,
+    pub asterisk_id: String,
+    pub application: String,
 }
 
 impl LiveRecording {
     #[instrument(level = "debug")]
-    pub async fn get(_recording_name: &str) -> Result<LiveRecording> {
-        unimplemented!()
+    pub async fn get(client: &Client, recording_name: &str) -> Result<LiveRecording> {
+        let url = recording_url(client, "live", recording_name, None)?;
+
+        let recording = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<LiveRecording>()
+            .await?;
+
+        event!(Level::INFO, "received live recording with name {}", recording.name);
+        Ok(recording)
     }
 
+    /// Stop the recording and throw away what has been recorded so far.
     #[instrument(level = "debug")]
-    pub async fn discard(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn discard(&self, client: &Client) -> Result<()> {
+        let url = recording_url(client, "live", &self.name, None)?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "discarded live recording with name {}", self.name);
+        Ok(())
     }
 
-    // TODO: explore if it's possible to return a StoredRecording
+    /// Stop the recording and wait for Asterisk to store it.
+    ///
+    /// `Client::run` must be running for the `RecordingFinished` event to arrive.
     #[instrument(level = "debug")]
-    pub async fn stop(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn stop(&self, client: &Client) -> Result<StoredRecording> {
+        let url = recording_url(client, "live", &self.name, Some("stop"))?;
+        let events = client.recording_events(&self.name);
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "stopped live recording with name {}", self.name);
+
+        self.wait(events).await
+    }
+
+    /// Wait until the recording ends on its own, e.g. on silence, a terminating DTMF or the maximum duration.
+    ///
+    /// Resolves to the stored recording, or [`AriError::RecordingFailed`] when Asterisk reports a failure
+    /// or the recording was discarded. `Client::run` must be running for the recording events to arrive.
+    #[instrument(level = "debug")]
+    pub async fn finished(&self, client: &Client) -> Result<StoredRecording> {
+        let events = client.recording_events(&self.name);
+
+        match LiveRecording::get(client, &self.name).await {
+            Ok(recording) => match recording.state {
+                RecordingState::Done => return Ok(recording.into()),
+                RecordingState::Failed | RecordingState::Canceled => return Err(recording.into_error()),
+                _ => {}
+            },
+            Err(AriError::NotFound { .. }) => {
+                return match StoredRecording::get(client, &self.name).await {
+                    Ok(recording) => Ok(recording),
+                    Err(AriError::NotFound { .. }) => Err(AriError::RecordingFailed {
+                        name: self.name.clone(),
+                        cause: None,
+                    }),
+                    Err(e) => Err(e),
+                };
+            }
+            Err(e) => return Err(e),
+        }
+
+        self.wait(events).await
+    }
+
+    async fn wait(&self, mut events: EventStream) -> Result<StoredRecording> {
+        while let Some(event) = events.next().await {
+            match event {
+                Event::RecordingFinished(e) => {
+                    event!(Level::INFO, "live recording with name {} finished", self.name);
+                    return Ok(e.recording.into());
+                }
+                Event::RecordingFailed(e) => {
+                    event!(Level::ERROR, "live recording with name {} failed", self.name);
+                    return Err(e.recording.into_error());
+                }
+                _ => {}
+            }
+        }
+
+        Err(AriError::Unknown(format!(
+            "event stream closed before recording {} finished",
+            self.name
+        )))
+    }
+
+    fn into_error(self) -> AriError {
+        AriError::RecordingFailed {
+            name: self.name,
+            cause: self
+                .cause
+                .or_else(|| (self.state == RecordingState::Canceled).then(|| "canceled".to_string())),
+        }
     }
 
     #[instrument(level = "debug")]
-    pub async fn pause(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn pause(&self, client: &Client) -> Result<()> {
+        let url = recording_url(client, "live", &self.name, Some("pause"))?;
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "paused live recording with name {}", self.name);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn resume(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn unpause(&self, client: &Client) -> Result<()> {
+        let url = recording_url(client, "live", &self.name, Some("pause"))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "unpaused live recording with name {}", self.name);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn mute(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn mute(&self, client: &Client) -> Result<()> {
+        let url = recording_url(client, "live", &self.name, Some("mute"))?;
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "muted live recording with name {}", self.name);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn unmute(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn unmute(&self, client: &Client) -> Result<()> {
+        let url = recording_url(client, "live", &self.name, Some("mute"))?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "unmuted live recording with name {}", self.name);
+        Ok(())
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct StoredRecording {
-    pub id: String,
+    pub name: String,
     pub format: String,
+    /// Only known for recordings obtained from a finished [`LiveRecording`].
+    pub duration: Option<i32>,
+    pub talking_duration: Option<i32>,
+    pub silence_duration: Option<i32>,
+    pub cause: Option<String>,
+}
+
+impl From<LiveRecording> for StoredRecording {
+    fn from(recording: LiveRecording) -> Self {
+        Self {
+            name: recording.name,
+            format: recording.format,
+            duration: recording.duration,
+            talking_duration: recording.talking_duration,
+            silence_duration: recording.silence_duration,
+            cause: recording.cause,
+        }
+    }
 }
 
 impl StoredRecording {
     #[instrument(level = "debug")]
-    pub async fn list(_client: &Client) -> Result<Vec<StoredRecording>> {
-        unimplemented!()
+    pub async fn list(client: &Client) -> Result<Vec<StoredRecording>> {
+        let url = client.url.join("recordings/stored")?;
+
+        let recordings = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<StoredRecording>>()
+            .await?;
+
+        event!(Level::INFO, "received stored recordings");
+        Ok(recordings)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, recording_name: &str) -> Result<StoredRecording> {
+        let url = recording_url(client, "stored", recording_name, None)?;
+
+        let recording = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<StoredRecording>()
+            .await?;
+
+        event!(Level::INFO, "received stored recording with name {}", recording.name);
+        Ok(recording)
     }
 
     #[instrument(level = "debug")]
-    pub async fn get(_recording_name: &str) -> Result<StoredRecording> {
-        unimplemented!()
+    pub async fn delete(&self, client: &Client) -> Result<()> {
+        let url = recording_url(client, "stored", &self.name, None)?;
+
+        client.delete(url).send().await?.ari_error_for_status().await?;
+        event!(Level::INFO, "deleted stored recording with name {}", self.name);
+        Ok(())
     }
 
     #[instrument(level = "debug")]
-    pub async fn delete(&self, _client: &Client) -> Result<()> {
-        unimplemented!()
+    pub async fn copy(&self, client: &Client, destination_recording_name: &str) -> Result<StoredRecording> {
+        let url = recording_url(client, "stored", &self.name, Some("copy"))?
+            .query_pairs_mut()
+            .append_pair("destinationRecordingName", destination_recording_name)
+            .finish()
+            .to_owned();
+
+        let recording = client
+            .post(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<StoredRecording>()
+            .await?;
+
+        event!(Level::INFO, "copied stored recording with name {} to {}", self.name, recording.name);
+        Ok(recording)
     }
 
+    /// Stream the recording file as Asterisk sends it, without buffering it in memory.
     #[instrument(level = "debug")]
-    pub async fn download(&self, _client: &Client) -> Result<&[u8]> {
-        unimplemented!()
+    pub async fn download(&self, client: &Client) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let url = recording_url(client, "stored", &self.name, Some("file"))?;
+
+        let response = client.get(url).send().await?.ari_error_for_status().await?;
+
+        event!(Level::INFO, "downloading stored recording with name {}", self.name);
+        Ok(response.bytes_stream().map(|chunk| chunk.map_err(AriError::from)))
+    }
+}
+
+/// Recording names may contain `/` for subdirectories, which has to be escaped in the path.
+fn recording_url(client: &Client, kind: &str, recording_name: &str, action: Option<&str>) -> Result<Url> {
+    let path = format!("recordings/{}", kind);
+
+    match action {
+        Some(action) => client.segment_url(&path, &[recording_name, action]),
+        None => client.segment_url(&path, &[recording_name]),
     }
 }
@@ -1,7 +1,129 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::DateTime;
+use futures_util::{stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::mpsc::Sender,
+    task::JoinHandle,
+    time::{interval, MissedTickBehavior},
+};
+use tracing::{event, Level};
+
+use crate::{channel::Channel, client::Client};
 
-#[derive(Serialize, Deserialize, Debug)]
+/// RTP statistics of a channel, jitter in seconds and round trip times in milliseconds.
+///
+/// MES fields are only reported by Asterisk 21 and later.
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct RtpStatistics {
-    pub id: String,
+    pub channel_uniqueid: String,
+    pub txcount: u64,
+    pub rxcount: u64,
+    pub txjitter: Option<f64>,
+    pub rxjitter: Option<f64>,
+    pub remote_maxjitter: Option<f64>,
+    pub remote_minjitter: Option<f64>,
+    pub remote_normdevjitter: Option<f64>,
+    pub remote_stdevjitter: Option<f64>,
+    pub local_maxjitter: Option<f64>,
+    pub local_minjitter: Option<f64>,
+    pub local_normdevjitter: Option<f64>,
+    pub local_stdevjitter: Option<f64>,
+    pub txploss: u64,
+    pub rxploss: u64,
+    pub remote_maxrxploss: Option<f64>,
+    pub remote_minrxploss: Option<f64>,
+    pub remote_normdevrxploss: Option<f64>,
+    pub remote_stdevrxploss: Option<f64>,
+    pub local_maxrxploss: Option<f64>,
+    pub local_minrxploss: Option<f64>,
+    pub local_normdevrxploss: Option<f64>,
+    pub local_stdevrxploss: Option<f64>,
+    pub rtt: Option<f64>,
+    pub maxrtt: Option<f64>,
+    pub minrtt: Option<f64>,
+    pub normdevrtt: Option<f64>,
+    pub stdevrtt: Option<f64>,
+    pub txmes: Option<f64>,
+    pub rxmes: Option<f64>,
+    pub remote_maxmes: Option<f64>,
+    pub remote_minmes: Option<f64>,
+    pub remote_normdevmes: Option<f64>,
+    pub remote_stdevmes: Option<f64>,
+    pub local_maxmes: Option<f64>,
+    pub local_minmes: Option<f64>,
+    pub local_normdevmes: Option<f64>,
+    pub local_stdevmes: Option<f64>,
+    pub local_ssrc: u32,
+    pub remote_ssrc: u32,
+    pub txoctetcount: u64,
+    pub rxoctetcount: u64,
+}
+
+/// Maximum number of RTP statistics requests [`spawn_sampler`] has in flight at once.
+const MAX_CONCURRENT_SAMPLES: usize = 16;
+
+/// Statistics of one channel collected by [`spawn_sampler`].
+#[derive(Debug, Clone)]
+pub struct QualityReport {
+    pub timestamp: DateTime<chrono::Utc>,
+    pub channel_id: String,
+    pub channel_name: String,
+    pub statistics: RtpStatistics,
+}
+
+/// Poll the RTP statistics of every active channel each `period` and send a report per channel to `tx`.
+///
+/// Channels without RTP, such as local channels, are skipped. A round taking longer than `period` delays
+/// the next one instead of causing a burst of catch-up rounds. The task stops once `tx` is closed.
+pub fn spawn_sampler(client: Arc<Client>, period: Duration, tx: Sender<QualityReport>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let client = client.as_ref();
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        while !tx.is_closed() {
+            ticker.tick().await;
+
+            let channels = match Channel::list(client).await {
+                Ok(channels) => channels,
+                Err(e) => {
+                    event!(Level::WARN, "Failed to list channels for RTP sampling: {}", e);
+                    continue;
+                }
+            };
+
+            let mut samples = stream::iter(channels)
+                .map(|channel| async move {
+                    let statistics = channel.get_rtp_statistics(client).await;
+                    (channel, statistics)
+                })
+                .buffer_unordered(MAX_CONCURRENT_SAMPLES);
+
+            while let Some((channel, statistics)) = samples.next().await {
+                let statistics = match statistics {
+                    Ok(statistics) => statistics,
+                    Err(e) => {
+                        event!(Level::TRACE, "No RTP statistics for channel {}: {}", channel.id, e);
+                        continue;
+                    }
+                };
+
+                let report = QualityReport {
+                    timestamp: chrono::Utc::now(),
+                    channel_id: channel.id,
+                    channel_name: channel.name,
+                    statistics,
+                };
+
+                if tx.send(report).await.is_err() {
+                    break;
+                }
+            }
+        }
+
+        event!(Level::INFO, "RTP statistics sampler stopped");
+    })
 }
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use tracing::{event, instrument, Level};
+
+use crate::{client::Client, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct Sound {
+    pub id: String,
+    pub text: Option<String>,
+    pub formats: Vec<FormatLangPair>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct FormatLangPair {
+    pub language: String,
+    pub format: String,
+}
+
+impl Sound {
+    /// List the sounds installed on Asterisk, optionally only those available in `lang` and/or `format`.
+    #[instrument(level = "debug")]
+    pub async fn list(client: &Client, lang: Option<&str>, format: Option<&str>) -> Result<Vec<Sound>> {
+        let mut url = client.url.join("sounds")?;
+        {
+            let mut query = url.query_pairs_mut();
+
+            if let Some(lang) = lang {
+                query.append_pair("lang", lang);
+            }
+
+            if let Some(format) = format {
+                query.append_pair("format", format);
+            }
+        }
+
+        let sounds = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await?
+            .json::<Vec<Sound>>()
+            .await?;
+        event!(Level::INFO, "received sounds");
+        Ok(sounds)
+    }
+
+    #[instrument(level = "debug")]
+    pub async fn get(client: &Client, sound_id: &str) -> Result<Sound> {
+        let url = client.segment_url("sounds", &[sound_id])?;
+
+        let sound = client.get(url).send().await?.ari_error_for_status().await?.json::<Sound>().await?;
+        event!(Level::INFO, "received sound {}", sound.id);
+        Ok(sound)
+    }
+
+    /// Whether the sound is available in `language`, in any format.
+    pub fn has_language(&self, language: &str) -> bool {
+        self.formats.iter().any(|pair| pair.language == language)
+    }
+}
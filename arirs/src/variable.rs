@@ -1,7 +1,65 @@
 use serde::{Deserialize, Serialize};
+use tracing::{event, instrument, Level};
 
-#[derive(Serialize, Deserialize, Debug)]
+use crate::{client::Client, AriError, ResponseExt, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct Variable {
-    pub id: String,
+    pub value: String,
+}
+
+impl Variable {
+    #[instrument(level = "debug")]
+    pub async fn get_global(client: &Client, variable: &str) -> Result<Variable> {
+        let url = client
+            .url
+            .join("asterisk/variable")?
+            .query_pairs_mut()
+            .append_pair("variable", variable)
+            .finish()
+            .to_owned();
+
+        let value = client
+            .get(url)
+            .send()
+            .await?
+            .ari_error_for_status()
+            .await
+            .map_err(|e| Variable::not_found(e, variable))?
+            .json::<Variable>()
+            .await?;
+
+        event!(Level::INFO, "received global variable {}", variable);
+        Ok(value)
+    }
+
+    /// Set a global variable, or unset it when `value` is `None`.
+    #[instrument(level = "debug")]
+    pub async fn set_global(client: &Client, variable: &str, value: Option<&str>) -> Result<()> {
+        let mut url = client.url.join("asterisk/variable")?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("variable", variable);
+
+            if let Some(value) = value {
+                query.append_pair("value", value);
+            }
+        }
+
+        client.post(url).send().await?.ari_error_for_status().await?;
+
+        event!(Level::INFO, "set global variable {}", variable);
+        Ok(())
+    }
+
+    /// Asterisk answers 404 both for an unknown resource and an unknown variable, tell them apart by the message.
+    pub(crate) fn not_found(err: AriError, variable: &str) -> AriError {
+        match err {
+            AriError::NotFound { message, .. } if message == "Provided variable was not found" => {
+                AriError::VariableNotFound(variable.to_string())
+            }
+            err => err,
+        }
+    }
 }